@@ -68,7 +68,7 @@ fn main() -> ! {
     loop {
         let received = block!(rx.read()).unwrap();
 
-        if let Some(received_frame) = frame_buffer.push(received) {
+        if let Ok(Some(received_frame)) = frame_buffer.receive(received) {
             // Don't respond to anything except a received packet
             if !ZigbeeReceivePacket::respond_to(received_frame.data[0]) {
                 continue;
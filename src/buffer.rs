@@ -1,5 +1,6 @@
 use core::num::Wrapping;
-use super::frame::Frame;
+use core::convert::TryFrom;
+use super::frame::{ self, Frame, FrameBuf, ApiMode };
 
 #[derive(Debug)]
 pub enum FrameBufferState {
@@ -10,59 +11,247 @@ pub enum FrameBufferState {
   ReceivingChecksum
 }
 
+/// The ways in which receiving a frame can fail.
+#[derive(Debug, PartialEq)]
+pub enum FrameError {
+  /// The checksum byte did not match the received data.
+  ChecksumError,
+  /// The length field is larger than the borrowed buffer.
+  FrameTooLargeError,
+  /// The length field was zero.
+  ZeroLengthError,
+  /// A start delimiter arrived in the middle of an escaped frame.
+  UnexpectedDelimiterError
+}
+
 /// A state machine that handles receiving bytes coming from an XBee and
 /// alerting the consuming application when a full frame has been received.
 pub struct FrameBuffer <'a> {
+  decoder: FrameDecoder,
+  buffer: &'a mut [u8]
+}
+
+impl <'a> FrameBuffer <'a> {
+  /// Create a new `FrameBuffer` with borrowed array space `buffer`.
+  pub fn new(buffer: &'a mut [u8]) -> Self {
+    Self::with_mode(buffer, ApiMode::Unescaped)
+  }
+
+  /// Create a new `FrameBuffer` with borrowed array space `buffer` that
+  /// receives frames sent in the given API `mode`.
+  pub fn with_mode(buffer: &'a mut [u8], mode: ApiMode) -> Self {
+    Self { decoder: FrameDecoder::new(mode), buffer }
+  }
+
+  /// The number of received bytes that did not end up in a frame, either
+  /// because they arrived outside of a frame or because they were skipped
+  /// while resynchronizing.
+  pub fn discarded_bytes(&self) -> u32 {
+    self.decoder.discarded
+  }
+
+  /// Resets the discarded byte counter to zero.
+  pub fn clear_discarded_bytes(&mut self) {
+    self.decoder.discarded = 0;
+  }
+
+  /// Receive a single byte of data from the XBee device and return
+  /// a Frame if completed. Malformed frames are reported as a
+  /// `FrameError`.
+  ///
+  /// In escaped mode a raw delimiter always starts a new frame, so an
+  /// interrupted frame is reported and receiving restarts right away. In
+  /// unescaped mode a frame that fails its checksum is searched for the
+  /// next delimiter and its remaining bytes are received again, in which
+  /// case a frame recovered that way is returned instead of the error.
+  pub fn receive(&mut self, received: u8) -> Result<Option<Frame<'_>>, FrameError> {
+    match self.decoder.receive(self.buffer, received)? {
+      Some(count) => Ok(Some(Frame::new(&self.buffer[0..count]))),
+      None => Ok(None)
+    }
+  }
+
+  /// Receives the bytes of `input` up to the end of the next completed
+  /// frame, or the next error, and returns it along with the rest of the
+  /// input. Returns `None` once the input runs out, keeping a partially
+  /// received frame for the next call.
+  ///
+  /// A valid frame that lies entirely within `input` and contains no
+  /// escaped bytes is not copied; it points straight into `input`.
+  pub fn receive_slice<'c, 'b: 'c>(&'c mut self, input: &'b [u8]) -> Option<(Result<Frame<'c>, FrameError>, &'b [u8])> {
+    let mut index = 0;
+
+    while index < input.len() {
+      if let Some(length) = self.decoder.contiguous_frame(self.buffer.len(), &input[index..]) {
+        let frame = Frame::new(&input[index + 3..index + 3 + length]);
+        return Some((Ok(frame), &input[index + 4 + length..]));
+      }
+
+      let rest = &input[index + 1..];
+      match self.decoder.receive(self.buffer, input[index]) {
+        Ok(None) => index += 1,
+        Ok(Some(count)) => return Some((Ok(Frame::new(&self.buffer[0..count])), rest)),
+        Err(error) => return Some((Err(error), rest))
+      }
+    }
+
+    None
+  }
+
+  /// Like `receive`, but copies a completed frame into an owned `FrameBuf`
+  /// so that it outlives the next received byte. A frame larger than `N`
+  /// bytes is reported as `FrameTooLargeError`.
+  pub fn receive_owned<const N: usize>(&mut self, received: u8) -> Result<Option<FrameBuf<N>>, FrameError> {
+    match self.receive(received)? {
+      Some(frame) => FrameBuf::try_from(frame).map(Some).map_err(|_| FrameError::FrameTooLargeError),
+      None => Ok(None)
+    }
+  }
+}
+
+/// The state of a frame being received, kept apart from the buffer it is
+/// received into so that the buffer can be owned by whoever holds this.
+pub(crate) struct FrameDecoder {
   // The state of the receive buffer
   state: FrameBufferState,
+  mode: ApiMode,
+
+  // Set when the last byte was an escape in escaped API mode
+  escape_next: bool,
 
-  // The buffer and current index info
-  buffer: &'a mut [u8],
+  // The current index into the buffer
   count: usize,
 
-  // Save how many bytes there are left to read
+  // The declared length and how many bytes there are left to read
+  length: u16,
   left: u16,
 
   // Checksum related data
-  checksum: Wrapping<u8>
+  checksum: Wrapping<u8>,
+
+  // Raw bytes belonging to the current frame and bytes thrown away so far
+  frame_bytes: u32,
+  pub(crate) discarded: u32
 }
 
-impl <'a> FrameBuffer <'a> {
-  /// Create a new `FrameBuffer` with borrowed array space `buffer`.
-  pub fn new(buffer: &'a mut [u8]) -> Self {
+impl FrameDecoder {
+  pub(crate) fn new(mode: ApiMode) -> Self {
     Self {
       state: FrameBufferState::WaitingForDelimiter,
-      buffer: buffer,
+      mode,
+      escape_next: false,
       count: 0,
+      length: 0,
       left: 0,
-      checksum: Wrapping(0)
+      checksum: Wrapping(0),
+      frame_bytes: 0,
+      discarded: 0
     }
   }
 
-  /// Receive a single byte of data from the XBee device and return
-  /// a Frame if completed.
-  pub fn receive(&mut self, received: u8) -> Option<Frame> {
+  /// Receives a single byte into `buffer`, returning the length of the
+  /// frame in it once one is complete. See `FrameBuffer::receive`.
+  pub(crate) fn receive(&mut self, buffer: &mut [u8], received: u8) -> Result<Option<usize>, FrameError> {
+    match self.step(buffer, received) {
+      Err(FrameError::ChecksumError) => match self.resynchronize(buffer, received) {
+        Some(count) => Ok(Some(count)),
+        None => Err(FrameError::ChecksumError)
+      },
+      result => result
+    }
+  }
+
+  /// Returns the data length of the frame at the start of `input` if it
+  /// can be received without copying: nothing else is being received, the
+  /// whole frame is there, it needs no unescaping and its checksum is
+  /// correct. Anything else is left to the state machine.
+  pub(crate) fn contiguous_frame(&self, capacity: usize, input: &[u8]) -> Option<usize> {
+    if !matches!(self.state, FrameBufferState::WaitingForDelimiter) { return None; }
+    if input.len() < 4 || input[0] != frame::START_DELIMITER { return None; }
+
+    let length = input[1] as usize * 256 + input[2] as usize;
+    if length == 0 || length > capacity || input.len() < length + 4 { return None; }
+
+    let raw = &input[1..length + 4];
+    if self.mode == ApiMode::Escaped && raw.iter().any(|byte| *byte == frame::ESCAPE || *byte == frame::START_DELIMITER) {
+      return None;
+    }
+
+    let sum = raw[2..].iter().fold(Wrapping(0u8), |sum, byte| sum + Wrapping(*byte));
+    if sum.0 != 0xFF { return None; }
+
+    Some(length)
+  }
+
+  /// Advances the state machine by one byte, returning the length of the
+  /// frame in `buffer` once one is complete. On a checksum error the
+  /// received frame is left in place for `resynchronize`.
+  fn step(&mut self, buffer: &mut [u8], received: u8) -> Result<Option<usize>, FrameError> {
+    let mut received = received;
+
+    let in_frame = !matches!(self.state, FrameBufferState::WaitingForDelimiter);
+    if in_frame {
+      self.frame_bytes = self.frame_bytes.wrapping_add(1);
+    }
+
+    // Every byte after the delimiter may be escaped in escaped mode
+    if self.mode == ApiMode::Escaped && in_frame {
+      // A raw delimiter can never be part of an escaped frame, so it has
+      // to be the start of the next one
+      if received == frame::START_DELIMITER {
+        self.frame_bytes -= 1;
+        self.discard();
+        self.state = FrameBufferState::ReceivingLengthMsb;
+        self.frame_bytes = 1;
+
+        return Err(FrameError::UnexpectedDelimiterError);
+      }
+
+      if self.escape_next {
+        self.escape_next = false;
+        received = frame::escape(received);
+      } else if received == frame::ESCAPE {
+        self.escape_next = true;
+        return Ok(None);
+      }
+    }
+
     match self.state {
       FrameBufferState::WaitingForDelimiter => {
-        if received == 0x7E {
+        if received == frame::START_DELIMITER {
           self.state = FrameBufferState::ReceivingLengthMsb;
+          self.frame_bytes = 1;
+        } else {
+          self.discarded = self.discarded.wrapping_add(1);
         }
 
-        None
+        Ok(None)
       },
 
       FrameBufferState::ReceivingLengthMsb => {
-        self.left += (received as u16) << 8;
+        self.length = (received as u16) << 8;
         self.state = FrameBufferState::ReceivingLengthLsb;
 
-        None
+        Ok(None)
       },
 
       FrameBufferState::ReceivingLengthLsb => {
-        self.left += received as u16;
+        self.length += received as u16;
+        self.left = self.length;
+
+        if self.left == 0 {
+          self.discard();
+          return Err(FrameError::ZeroLengthError);
+        }
+
+        if self.left as usize > buffer.len() {
+          self.discard();
+          return Err(FrameError::FrameTooLargeError);
+        }
+
         self.state = FrameBufferState::ReceivingData;
 
-        None
+        Ok(None)
       },
 
       FrameBufferState::ReceivingData => {
@@ -71,30 +260,95 @@ impl <'a> FrameBuffer <'a> {
           self.state = FrameBufferState::ReceivingChecksum;
         }
 
-        self.buffer[self.count] = received;
+        buffer[self.count] = received;
         self.checksum += Wrapping(received);
         self.count += 1;
 
-        None
+        Ok(None)
       },
 
       FrameBufferState::ReceivingChecksum => {
-        let return_val = if received == 0xFF - self.checksum.0 {
-          Some(Frame::new(&self.buffer[0..self.count]))
-        } else {
-          None
-        };
+        if received != 0xFF - self.checksum.0 {
+          return Err(FrameError::ChecksumError);
+        }
 
-        // Reset everything
-        self.state = FrameBufferState::WaitingForDelimiter;
-        self.count = 0;
-        self.left = 0;
-        self.checksum = Wrapping(0);
+        let count = self.count;
+        self.frame_bytes = 0;
+        self.reset();
 
-        return_val
+        Ok(Some(count))
       }
     }
   }
+
+  /// Called after a checksum error. In escaped mode the frame is simply
+  /// thrown away. In unescaped mode the delimiter may have been a payload
+  /// byte of a frame we lost the start of, so the bytes after the next
+  /// delimiter are fed back through the state machine.
+  fn resynchronize(&mut self, buffer: &mut [u8], checksum: u8) -> Option<usize> {
+    if self.mode == ApiMode::Escaped {
+      self.discard();
+      return None;
+    }
+
+    // The failed frame as it came in: delimiter, length, data and checksum
+    let length = self.length;
+    let count = self.count;
+    let total = count + 4;
+    let raw = |buffer: &[u8], index: usize| match index {
+      0 => frame::START_DELIMITER,
+      1 => (length >> 8) as u8,
+      2 => (length & 0xFF) as u8,
+      _ if index == total - 1 => checksum,
+      _ => buffer[index - 3]
+    };
+
+    let start = (1..total).find(|index| raw(buffer, *index) == frame::START_DELIMITER);
+    let start = match start {
+      Some(start) => start,
+      None => {
+        self.discard();
+        return None;
+      }
+    };
+
+    self.discarded = self.discarded.wrapping_add(start as u32);
+    self.reset();
+    self.state = FrameBufferState::ReceivingLengthMsb;
+    self.frame_bytes = 1;
+
+    // Replaying only ever writes to the buffer behind the byte being read
+    for index in start + 1..total {
+      let byte = raw(buffer, index);
+
+      match self.step(buffer, byte) {
+        Ok(Some(received)) => {
+          self.discarded = self.discarded.wrapping_add((total - index - 1) as u32);
+          return Some(received);
+        },
+        Err(FrameError::ChecksumError) => self.discard(),
+        _ => {}
+      }
+    }
+
+    None
+  }
+
+  /// Throws away the current frame, counting its bytes as discarded.
+  fn discard(&mut self) {
+    self.discarded = self.discarded.wrapping_add(self.frame_bytes);
+    self.frame_bytes = 0;
+    self.reset();
+  }
+
+  /// Drop any partially received frame and wait for the next delimiter.
+  fn reset(&mut self) {
+    self.state = FrameBufferState::WaitingForDelimiter;
+    self.escape_next = false;
+    self.count = 0;
+    self.left = 0;
+    self.checksum = Wrapping(0);
+  }
 }
 
 #[test]
@@ -114,12 +368,245 @@ fn test_buffer_receive() {
 
   // Iterate through and check that they are equal
   for character in send_frame.serialize() {
-    if let Some(received_frame) = frame_buffer.receive(character) {
+    if let Some(received_frame) = frame_buffer.receive(character).unwrap() {
       assert_eq!(send_frame.data, received_frame.data);
       return;
     }
   }
 
   // If we hit here, the frame was never built
-  assert!(false);
+  panic!("no frame was received");
+}
+
+#[test]
+fn test_buffer_receive_escaped() {
+  let mut receive_buffer: [u8; 100] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::with_mode(&mut receive_buffer, ApiMode::Escaped);
+
+  // Every reserved byte shows up in the payload and the length
+  let data: [u8; 17] = [
+    0x7E, 0x7D, 0x11, 0x13, 0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E
+  ];
+  let send_frame = Frame::new(&data);
+
+  let mut received = false;
+  for character in send_frame.serialize_with_mode(ApiMode::Escaped) {
+    if let Some(received_frame) = frame_buffer.receive(character).unwrap() {
+      assert_eq!(send_frame.data, received_frame.data);
+      received = true;
+    }
+  }
+
+  assert!(received);
+}
+
+#[test]
+fn test_buffer_checksum_error() {
+  let mut receive_buffer: [u8; 10] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  let incoming: [u8; 8] = [0x7E, 0x00, 0x04, 0x08, 0x52, 0x4E, 0x4A, 0x0E];
+  for character in &incoming[..7] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  assert_eq!(frame_buffer.receive(incoming[7]), Err(FrameError::ChecksumError));
+}
+
+#[test]
+fn test_buffer_frame_too_large() {
+  let mut receive_buffer: [u8; 3] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  assert_eq!(frame_buffer.receive(0x7E), Ok(None));
+  assert_eq!(frame_buffer.receive(0x00), Ok(None));
+  assert_eq!(frame_buffer.receive(0x04), Err(FrameError::FrameTooLargeError));
+
+  // The buffer should be usable again afterwards
+  let incoming: [u8; 7] = [0x7E, 0x00, 0x03, 0x08, 0x52, 0x4E, 0x57];
+  for character in &incoming[..6] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  let frame = frame_buffer.receive(incoming[6]).unwrap().unwrap();
+  assert_eq!(frame.data, [0x08, 0x52, 0x4E]);
+}
+
+#[test]
+fn test_buffer_zero_length() {
+  let mut receive_buffer: [u8; 10] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  assert_eq!(frame_buffer.receive(0x7E), Ok(None));
+  assert_eq!(frame_buffer.receive(0x00), Ok(None));
+  assert_eq!(frame_buffer.receive(0x00), Err(FrameError::ZeroLengthError));
+}
+
+#[test]
+fn test_buffer_unexpected_delimiter() {
+  let mut receive_buffer: [u8; 10] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::with_mode(&mut receive_buffer, ApiMode::Escaped);
+
+  assert_eq!(frame_buffer.receive(0x7E), Ok(None));
+  assert_eq!(frame_buffer.receive(0x00), Ok(None));
+  assert_eq!(frame_buffer.receive(0x04), Ok(None));
+  assert_eq!(frame_buffer.receive(0x08), Ok(None));
+  assert_eq!(frame_buffer.receive(0x7E), Err(FrameError::UnexpectedDelimiterError));
+}
+
+#[test]
+fn test_buffer_resync_escaped() {
+  let mut receive_buffer: [u8; 10] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::with_mode(&mut receive_buffer, ApiMode::Escaped);
+
+  // The start of a frame followed by a complete frame
+  let incoming: [u8; 13] = [
+    0x7E, 0x00, 0x05, 0x08, 0x01,
+    0x7E, 0x00, 0x04, 0x08, 0x01, 0x4E, 0x4A, 0x5E
+  ];
+
+  for character in &incoming[..5] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  assert_eq!(frame_buffer.receive(incoming[5]), Err(FrameError::UnexpectedDelimiterError));
+
+  for character in &incoming[6..12] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  let frame = frame_buffer.receive(incoming[12]).unwrap().unwrap();
+  assert_eq!(frame.data, [0x08, 0x01, 0x4E, 0x4A]);
+  assert_eq!(frame_buffer.discarded_bytes(), 5);
+}
+
+#[test]
+fn test_buffer_resync_lost_byte() {
+  let mut receive_buffer: [u8; 10] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  // The first frame lost its frame id, so it runs into the second frame
+  let incoming: [u8; 15] = [
+    0x7E, 0x00, 0x04, 0x08, 0x4E, 0x4A, 0x0D,
+    0x7E, 0x00, 0x04, 0x08, 0x01, 0x4E, 0x4A, 0x5E
+  ];
+
+  for character in &incoming[..7] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  assert_eq!(frame_buffer.receive(incoming[7]), Err(FrameError::ChecksumError));
+
+  for character in &incoming[8..14] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  let frame = frame_buffer.receive(incoming[14]).unwrap().unwrap();
+  assert_eq!(frame.data, [0x08, 0x01, 0x4E, 0x4A]);
+  assert_eq!(frame_buffer.discarded_bytes(), 7);
+}
+
+#[test]
+fn test_buffer_resync_replays_frame() {
+  let mut receive_buffer: [u8; 20] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  // A corrupted length swallows a complete frame and a few more bytes
+  let incoming: [u8; 16] = [
+    0x7E, 0x00, 0x0C,
+    0x7E, 0x00, 0x04, 0x08, 0x01, 0x4E, 0x4A, 0x5E,
+    0x01, 0x02, 0x03, 0x04,
+    0x00
+  ];
+
+  for character in &incoming[..15] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  let frame = frame_buffer.receive(incoming[15]).unwrap().unwrap();
+  assert_eq!(frame.data, [0x08, 0x01, 0x4E, 0x4A]);
+  assert_eq!(frame_buffer.discarded_bytes(), 8);
+}
+
+#[test]
+fn test_buffer_discards_noise() {
+  let mut receive_buffer: [u8; 10] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  for character in &[0x00, 0x13, 0xFF] {
+    assert_eq!(frame_buffer.receive(*character), Ok(None));
+  }
+
+  assert_eq!(frame_buffer.discarded_bytes(), 3);
+  frame_buffer.clear_discarded_bytes();
+  assert_eq!(frame_buffer.discarded_bytes(), 0);
+}
+
+#[test]
+fn test_buffer_receive_owned() {
+  let mut receive_buffer: [u8; 100] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+  let serialized: [u8; 9] = [0x7E, 0x00, 0x05, 0x08, 0x01, 0x4E, 0x4A, 0xFF, 0x5F];
+
+  let mut received: Option<FrameBuf<8>> = None;
+  for character in serialized.iter() {
+    if let Some(frame) = frame_buffer.receive_owned(*character).unwrap() {
+      received = Some(frame);
+    }
+  }
+
+  match received {
+    Some(frame) => assert_eq!(frame.data(), [0x08, 0x01, 0x4E, 0x4A, 0xFF]),
+    None => panic!("no frame was received")
+  }
+
+  // The frame fits in the FrameBuffer but not in the FrameBuf
+  for character in serialized[..8].iter() {
+    assert_eq!(frame_buffer.receive_owned::<4>(*character), Ok(None));
+  }
+  assert_eq!(frame_buffer.receive_owned::<4>(serialized[8]), Err(FrameError::FrameTooLargeError));
+}
+
+#[test]
+fn test_buffer_receive_slice() {
+  let mut receive_buffer: [u8; 100] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::new(&mut receive_buffer);
+
+  // Noise, a whole frame, a frame with a bad checksum and the start of a third
+  let chunk: [u8; 24] = [
+    0x00,
+    0x7E, 0x00, 0x05, 0x08, 0x01, 0x4E, 0x4A, 0xFF, 0x5F,
+    0x7E, 0x00, 0x05, 0x08, 0x01, 0x4E, 0x4A, 0xFF, 0x00,
+    0x7E, 0x00, 0x05, 0x08, 0x02
+  ];
+
+  let (frame, rest) = frame_buffer.receive_slice(&chunk).unwrap();
+  let frame = frame.unwrap();
+  assert_eq!(frame.data, [0x08, 0x01, 0x4E, 0x4A, 0xFF]);
+  assert_eq!(frame.data.as_ptr(), chunk[4..].as_ptr());
+  assert_eq!(rest.len(), 14);
+
+  let (frame, rest) = frame_buffer.receive_slice(rest).unwrap();
+  assert_eq!(frame, Err(FrameError::ChecksumError));
+  assert!(frame_buffer.receive_slice(rest).is_none());
+
+  // The third frame is completed by the next chunk
+  let chunk: [u8; 4] = [0x4E, 0x4A, 0xFF, 0x5E];
+  let (frame, rest) = frame_buffer.receive_slice(&chunk).unwrap();
+  assert_eq!(frame.unwrap().data, [0x08, 0x02, 0x4E, 0x4A, 0xFF]);
+  assert!(rest.is_empty());
+  assert_eq!(frame_buffer.discarded_bytes(), 10);
+}
+
+#[test]
+fn test_buffer_receive_slice_escaped() {
+  let mut receive_buffer: [u8; 100] = unsafe { core::mem::zeroed() };
+  let mut frame_buffer = FrameBuffer::with_mode(&mut receive_buffer, ApiMode::Escaped);
+
+  // An escaped byte in the data means the frame has to be copied
+  let chunk: [u8; 10] = [0x7E, 0x00, 0x02, 0x08, 0x7D, 0x31, 0xE6, 0x7E, 0x00, 0x01];
+  let (frame, rest) = frame_buffer.receive_slice(&chunk).unwrap();
+
+  assert_eq!(frame.unwrap().data, [0x08, 0x11]);
+  assert_eq!(rest, [0x7E, 0x00, 0x01]);
 }
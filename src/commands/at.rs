@@ -0,0 +1,715 @@
+//! A catalog of the commonly used AT command registers together with typed
+//! values for them. A `Value` can be turned into the `AtCommand` that sets
+//! it, and decoded back from the `AtCommandResponse` to a query.
+
+use super::{ AtCommand, BufferSizeError };
+use crate::frame::ApiMode;
+use crate::responses::{ AtCommandResponse, RemoteAtCommandResponse, RemoteNode, ResponseError };
+use core::convert::TryFrom;
+
+/// Defines `Register` together with its two character codes, so that
+/// `Register::code` and `TryFrom<[u8; 2]>` are built from a single list.
+macro_rules! registers {
+  ($($register:ident => $code:literal),* $(,)?) => {
+    /// The AT command registers with a typed `Value`.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Register {
+      $($register),*
+    }
+
+    impl Register {
+      /// The two character AT command for this register.
+      pub fn code(self) -> [u8; 2] {
+        match self {
+          $(Register::$register => *$code),*
+        }
+      }
+    }
+
+    impl TryFrom<[u8; 2]> for Register {
+      type Error = ResponseError;
+
+      fn try_from(code: [u8; 2]) -> Result<Self, ResponseError> {
+        match &code {
+          $($code => Ok(Register::$register),)*
+          _ => Err(ResponseError::EnumComparisonError)
+        }
+      }
+    }
+  };
+}
+
+registers! {
+  PanId => b"ID",
+  Channel => b"CH",
+  SerialNumberHigh => b"SH",
+  SerialNumberLow => b"SL",
+  NetworkAddress => b"MY",
+  NodeIdentifier => b"NI",
+  PowerLevel => b"PL",
+  ApiEnable => b"AP",
+  BaudRate => b"BD",
+  SleepMode => b"SM",
+  SleepPeriod => b"SP",
+  TimeBeforeSleep => b"ST",
+  EncryptionEnable => b"EE",
+  LinkKey => b"KY",
+  NetworkKey => b"NK",
+  ChannelVerification => b"JV",
+  CoordinatorEnable => b"CE",
+  ReceivedSignalStrength => b"DB",
+  FirmwareVersion => b"VR",
+  HardwareVersion => b"HV",
+  AssociationIndication => b"AI",
+  MaximumPayload => b"NP",
+  Write => b"WR",
+  ApplyChanges => b"AC",
+  RestoreDefaults => b"RE",
+  SoftwareReset => b"FR",
+  NodeDiscover => b"ND",
+  DestinationNode => b"DN"
+}
+
+impl Register {
+  /// Builds the `AtCommand` that queries this register, or executes it for
+  /// registers such as `WR` and `AC` that take no value.
+  pub fn query(self, frame_id: u8) -> AtCommand<'static> {
+    AtCommand::new(frame_id, self.code(), None)
+  }
+
+  /// Decodes the data returned for this register.
+  pub fn decode(self, data: &[u8]) -> Result<Value<'_>, ResponseError> {
+    let value = match self {
+      Register::PanId => Value::PanId(decode_number(data, 8)?),
+      Register::Channel => Value::Channel(decode_number(data, 1)? as u8),
+      Register::SerialNumberHigh => Value::SerialNumberHigh(decode_number(data, 4)? as u32),
+      Register::SerialNumberLow => Value::SerialNumberLow(decode_number(data, 4)? as u32),
+      Register::NetworkAddress => Value::NetworkAddress(decode_number(data, 2)? as u16),
+      Register::NodeIdentifier => Value::NodeIdentifier(decode_str(data)?),
+      Register::PowerLevel =>
+        Value::PowerLevel(PowerLevel::try_from(decode_number(data, 1)? as u8)?),
+      Register::ApiEnable =>
+        Value::ApiEnable(ApiEnable::try_from(decode_number(data, 1)? as u8)?),
+      Register::BaudRate =>
+        Value::BaudRate(BaudRate::try_from(decode_number(data, 1)? as u8)?),
+      Register::SleepMode =>
+        Value::SleepMode(SleepMode::try_from(decode_number(data, 1)? as u8)?),
+      Register::SleepPeriod => Value::SleepPeriod(decode_number(data, 2)? as u16),
+      Register::TimeBeforeSleep => Value::TimeBeforeSleep(decode_number(data, 2)? as u16),
+      Register::EncryptionEnable => Value::EncryptionEnable(decode_number(data, 1)? != 0),
+      Register::LinkKey => Value::LinkKey(data),
+      Register::NetworkKey => Value::NetworkKey(data),
+      Register::ChannelVerification => Value::ChannelVerification(decode_number(data, 1)? != 0),
+      Register::CoordinatorEnable => Value::CoordinatorEnable(decode_number(data, 1)? != 0),
+      Register::ReceivedSignalStrength =>
+        Value::ReceivedSignalStrength(decode_number(data, 1)? as u8),
+      Register::FirmwareVersion => Value::FirmwareVersion(decode_number(data, 2)? as u16),
+      Register::HardwareVersion => Value::HardwareVersion(decode_number(data, 2)? as u16),
+      Register::AssociationIndication =>
+        Value::AssociationIndication(AssociationIndication::from(decode_number(data, 1)? as u8)),
+      Register::MaximumPayload => Value::MaximumPayload(decode_number(data, 2)? as u16),
+      Register::Write => Value::Write,
+      Register::ApplyChanges => Value::ApplyChanges,
+      Register::RestoreDefaults => Value::RestoreDefaults,
+      Register::SoftwareReset => Value::SoftwareReset,
+      Register::NodeDiscover => Value::DiscoveredNode(RemoteNode::parse(data)?),
+      Register::DestinationNode => {
+        if data.len() != 10 { return Err(ResponseError::SizeIncorrectError); }
+
+        Value::DestinationAddress {
+          network_address: decode_number(&data[0..2], 2)? as u16,
+          address: decode_number(&data[2..10], 8)?
+        }
+      }
+    };
+
+    Ok(value)
+  }
+}
+
+/// The ways building the command that sets a `Value` can fail.
+#[derive(Debug, PartialEq)]
+pub enum SetValueError {
+  /// The value can only be read, such as a serial number or a node found by
+  /// `ND`.
+  ReadOnly,
+  /// `scratch` is too small for the encoded parameter.
+  BufferSize(BufferSizeError)
+}
+
+impl From<BufferSizeError> for SetValueError {
+  fn from(error: BufferSizeError) -> Self {
+    SetValueError::BufferSize(error)
+  }
+}
+
+/// A typed value for one of the registers in `Register`. Values are used
+/// both to set a register and as the result of decoding a query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value<'a> {
+  /// `ID`: the 64-bit extended PAN ID.
+  PanId(u64),
+  /// `CH`: the operating channel (read only).
+  Channel(u8),
+  /// `SH`: the upper 32 bits of the serial number (read only).
+  SerialNumberHigh(u32),
+  /// `SL`: the lower 32 bits of the serial number (read only).
+  SerialNumberLow(u32),
+  /// `MY`: the 16-bit network address (read only).
+  NetworkAddress(u16),
+  /// `NI`: the node identifier string, up to 20 characters.
+  NodeIdentifier(&'a str),
+  /// `PL`: the transmit power level.
+  PowerLevel(PowerLevel),
+  /// `AP`: the API mode.
+  ApiEnable(ApiEnable),
+  /// `BD`: the serial interface baud rate.
+  BaudRate(BaudRate),
+  /// `SM`: the sleep mode.
+  SleepMode(SleepMode),
+  /// `SP`: the cyclic sleep period in units of 10 ms.
+  SleepPeriod(u16),
+  /// `ST`: the time before sleep in ms.
+  TimeBeforeSleep(u16),
+  /// `EE`: whether encryption is enabled.
+  EncryptionEnable(bool),
+  /// `KY`: the 128-bit link key (write only).
+  LinkKey(&'a [u8]),
+  /// `NK`: the 128-bit network key (write only).
+  NetworkKey(&'a [u8]),
+  /// `JV`: whether routers verify the coordinator on their channel.
+  ChannelVerification(bool),
+  /// `CE`: whether the device acts as the coordinator.
+  CoordinatorEnable(bool),
+  /// `DB`: the RSSI of the last received packet in -dBm (read only).
+  ReceivedSignalStrength(u8),
+  /// `VR`: the firmware version (read only).
+  FirmwareVersion(u16),
+  /// `HV`: the hardware version (read only).
+  HardwareVersion(u16),
+  /// `AI`: the association status (read only).
+  AssociationIndication(AssociationIndication),
+  /// `NP`: the maximum RF payload in bytes (read only).
+  MaximumPayload(u16),
+  /// `WR`: write the current configuration to non-volatile memory.
+  Write,
+  /// `AC`: apply queued changes.
+  ApplyChanges,
+  /// `RE`: restore the factory defaults.
+  RestoreDefaults,
+  /// `FR`: perform a software reset.
+  SoftwareReset,
+  /// `ND`: discover nodes, optionally only the one with the given identifier.
+  NodeDiscover(Option<&'a str>),
+  /// One `ND` response, sent once for every discovered node.
+  DiscoveredNode(RemoteNode<'a>),
+  /// `DN`: resolve the node with the given identifier.
+  DestinationNode(&'a str),
+  /// The result of a successful `DN` lookup.
+  DestinationAddress { network_address: u16, address: u64 }
+}
+
+impl <'a> Value<'a> {
+  /// The register this value belongs to.
+  pub fn register(&self) -> Register {
+    match self {
+      Value::PanId(_) => Register::PanId,
+      Value::Channel(_) => Register::Channel,
+      Value::SerialNumberHigh(_) => Register::SerialNumberHigh,
+      Value::SerialNumberLow(_) => Register::SerialNumberLow,
+      Value::NetworkAddress(_) => Register::NetworkAddress,
+      Value::NodeIdentifier(_) => Register::NodeIdentifier,
+      Value::PowerLevel(_) => Register::PowerLevel,
+      Value::ApiEnable(_) => Register::ApiEnable,
+      Value::BaudRate(_) => Register::BaudRate,
+      Value::SleepMode(_) => Register::SleepMode,
+      Value::SleepPeriod(_) => Register::SleepPeriod,
+      Value::TimeBeforeSleep(_) => Register::TimeBeforeSleep,
+      Value::EncryptionEnable(_) => Register::EncryptionEnable,
+      Value::LinkKey(_) => Register::LinkKey,
+      Value::NetworkKey(_) => Register::NetworkKey,
+      Value::ChannelVerification(_) => Register::ChannelVerification,
+      Value::CoordinatorEnable(_) => Register::CoordinatorEnable,
+      Value::ReceivedSignalStrength(_) => Register::ReceivedSignalStrength,
+      Value::FirmwareVersion(_) => Register::FirmwareVersion,
+      Value::HardwareVersion(_) => Register::HardwareVersion,
+      Value::AssociationIndication(_) => Register::AssociationIndication,
+      Value::MaximumPayload(_) => Register::MaximumPayload,
+      Value::Write => Register::Write,
+      Value::ApplyChanges => Register::ApplyChanges,
+      Value::RestoreDefaults => Register::RestoreDefaults,
+      Value::SoftwareReset => Register::SoftwareReset,
+      Value::NodeDiscover(_) | Value::DiscoveredNode(_) => Register::NodeDiscover,
+      Value::DestinationNode(_) | Value::DestinationAddress { .. } => Register::DestinationNode
+    }
+  }
+
+  /// Encodes the parameter bytes for this value. Numbers are written to
+  /// `scratch` MSB first, while strings and keys are returned as they are.
+  pub fn fill_parameter<'b>(&'b self, scratch: &'b mut [u8]) -> Result<&'b [u8], BufferSizeError> {
+    match *self {
+      Value::PanId(value) => encode_number(value, 8, scratch),
+      Value::Channel(value) | Value::ReceivedSignalStrength(value) =>
+        encode_number(value as u64, 1, scratch),
+      Value::SerialNumberHigh(value) | Value::SerialNumberLow(value) =>
+        encode_number(value as u64, 4, scratch),
+      Value::NetworkAddress(value) | Value::SleepPeriod(value) | Value::TimeBeforeSleep(value) |
+      Value::FirmwareVersion(value) | Value::HardwareVersion(value) | Value::MaximumPayload(value) =>
+        encode_number(value as u64, 2, scratch),
+      Value::PowerLevel(value) => encode_number(value as u64, 1, scratch),
+      Value::ApiEnable(value) => encode_number(value as u64, 1, scratch),
+      Value::BaudRate(value) => encode_number(value as u64, 1, scratch),
+      Value::SleepMode(value) => encode_number(value as u64, 1, scratch),
+      Value::AssociationIndication(value) => encode_number(u8::from(value) as u64, 1, scratch),
+      Value::EncryptionEnable(value) | Value::ChannelVerification(value) |
+      Value::CoordinatorEnable(value) => encode_number(value as u64, 1, scratch),
+      Value::NodeIdentifier(value) | Value::DestinationNode(value) |
+      Value::NodeDiscover(Some(value)) => Ok(value.as_bytes()),
+      Value::LinkKey(value) | Value::NetworkKey(value) => Ok(value),
+      Value::DestinationAddress { network_address, address } => {
+        if scratch.len() < 10 { return Err(BufferSizeError); }
+
+        encode_number(network_address as u64, 2, &mut scratch[0..2])?;
+        encode_number(address, 8, &mut scratch[2..10])?;
+        Ok(&scratch[0..10])
+      },
+      Value::Write | Value::ApplyChanges | Value::RestoreDefaults | Value::SoftwareReset |
+      Value::NodeDiscover(None) | Value::DiscoveredNode(_) => Ok(&[])
+    }
+  }
+
+  /// Whether this value can only be read from the XBee, so that there is
+  /// no command to set it.
+  pub fn is_read_only(&self) -> bool {
+    matches!(
+      self,
+      Value::Channel(_) | Value::SerialNumberHigh(_) | Value::SerialNumberLow(_) |
+      Value::NetworkAddress(_) | Value::ReceivedSignalStrength(_) | Value::FirmwareVersion(_) |
+      Value::HardwareVersion(_) | Value::AssociationIndication(_) | Value::MaximumPayload(_) |
+      Value::DiscoveredNode(_) | Value::DestinationAddress { .. }
+    )
+  }
+
+  /// Builds the `AtCommand` that sets the register to this value, using
+  /// `scratch` to hold the encoded parameter. Eight bytes are enough for
+  /// every value that can be set. Read-only values are rejected.
+  pub fn to_command<'b>(&'b self, frame_id: u8, scratch: &'b mut [u8]) -> Result<AtCommand<'b>, SetValueError> {
+    if self.is_read_only() { return Err(SetValueError::ReadOnly); }
+
+    let parameter = self.fill_parameter(scratch)?;
+    let parameter = if parameter.is_empty() { None } else { Some(parameter) };
+
+    Ok(AtCommand::new(frame_id, self.register().code(), parameter))
+  }
+}
+
+impl <'a> TryFrom<&AtCommandResponse<'a>> for Value<'a> {
+  type Error = ResponseError;
+
+  /// Decodes the data of a response based on the command it answers. The
+  /// status of the response is not checked.
+  fn try_from(response: &AtCommandResponse<'a>) -> Result<Self, ResponseError> {
+    let register = Register::try_from(response.at_command)?;
+    register.decode(response.command_data.unwrap_or(&[]))
+  }
+}
+
+impl <'a> TryFrom<&RemoteAtCommandResponse<'a>> for Value<'a> {
+  type Error = ResponseError;
+
+  /// Decodes the data of a remote response based on the command it
+  /// answers. The status of the response is not checked.
+  fn try_from(response: &RemoteAtCommandResponse<'a>) -> Result<Self, ResponseError> {
+    let register = Register::try_from(response.at_command)?;
+    register.decode(response.command_data.unwrap_or(&[]))
+  }
+}
+
+/// `BD` values for the serial interface baud rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BaudRate {
+  B1200 = 0,
+  B2400 = 1,
+  B4800 = 2,
+  B9600 = 3,
+  B19200 = 4,
+  B38400 = 5,
+  B57600 = 6,
+  B115200 = 7,
+  B230400 = 8,
+  B460800 = 9,
+  B921600 = 10
+}
+
+impl BaudRate {
+  /// The baud rate in bits per second.
+  pub fn bits_per_second(self) -> u32 {
+    match self {
+      BaudRate::B1200 => 1200,
+      BaudRate::B2400 => 2400,
+      BaudRate::B4800 => 4800,
+      BaudRate::B9600 => 9600,
+      BaudRate::B19200 => 19200,
+      BaudRate::B38400 => 38400,
+      BaudRate::B57600 => 57600,
+      BaudRate::B115200 => 115_200,
+      BaudRate::B230400 => 230_400,
+      BaudRate::B460800 => 460_800,
+      BaudRate::B921600 => 921_600
+    }
+  }
+}
+
+impl TryFrom<u8> for BaudRate {
+  type Error = ResponseError;
+
+  fn try_from(value: u8) -> Result<Self, ResponseError> {
+    match value {
+      0 => Ok(BaudRate::B1200),
+      1 => Ok(BaudRate::B2400),
+      2 => Ok(BaudRate::B4800),
+      3 => Ok(BaudRate::B9600),
+      4 => Ok(BaudRate::B19200),
+      5 => Ok(BaudRate::B38400),
+      6 => Ok(BaudRate::B57600),
+      7 => Ok(BaudRate::B115200),
+      8 => Ok(BaudRate::B230400),
+      9 => Ok(BaudRate::B460800),
+      10 => Ok(BaudRate::B921600),
+      _ => Err(ResponseError::EnumComparisonError)
+    }
+  }
+}
+
+/// `PL` values for the transmit power level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerLevel {
+  Lowest = 0,
+  Low = 1,
+  Medium = 2,
+  High = 3,
+  Highest = 4
+}
+
+impl TryFrom<u8> for PowerLevel {
+  type Error = ResponseError;
+
+  fn try_from(value: u8) -> Result<Self, ResponseError> {
+    match value {
+      0 => Ok(PowerLevel::Lowest),
+      1 => Ok(PowerLevel::Low),
+      2 => Ok(PowerLevel::Medium),
+      3 => Ok(PowerLevel::High),
+      4 => Ok(PowerLevel::Highest),
+      _ => Err(ResponseError::EnumComparisonError)
+    }
+  }
+}
+
+/// `AP` values for the API mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApiEnable {
+  Transparent = 0,
+  Api = 1,
+  ApiEscaped = 2
+}
+
+impl ApiEnable {
+  /// The `ApiMode` frames have to be sent and received with, if any.
+  pub fn api_mode(self) -> Option<ApiMode> {
+    match self {
+      ApiEnable::Transparent => None,
+      ApiEnable::Api => Some(ApiMode::Unescaped),
+      ApiEnable::ApiEscaped => Some(ApiMode::Escaped)
+    }
+  }
+}
+
+impl TryFrom<u8> for ApiEnable {
+  type Error = ResponseError;
+
+  fn try_from(value: u8) -> Result<Self, ResponseError> {
+    match value {
+      0 => Ok(ApiEnable::Transparent),
+      1 => Ok(ApiEnable::Api),
+      2 => Ok(ApiEnable::ApiEscaped),
+      _ => Err(ResponseError::EnumComparisonError)
+    }
+  }
+}
+
+/// `SM` values for the sleep mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SleepMode {
+  NoSleep = 0,
+  PinHibernate = 1,
+  CyclicSleep = 4,
+  CyclicSleepPinWake = 5
+}
+
+impl TryFrom<u8> for SleepMode {
+  type Error = ResponseError;
+
+  fn try_from(value: u8) -> Result<Self, ResponseError> {
+    match value {
+      0 => Ok(SleepMode::NoSleep),
+      1 => Ok(SleepMode::PinHibernate),
+      4 => Ok(SleepMode::CyclicSleep),
+      5 => Ok(SleepMode::CyclicSleepPinWake),
+      _ => Err(ResponseError::EnumComparisonError)
+    }
+  }
+}
+
+/// `AI` values describing the state of joining or forming a network.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssociationIndication {
+  Associated,
+  NoPansFound,
+  NoValidPansFound,
+  JoiningNotAllowed,
+  NoJoinableBeacons,
+  UnexpectedState,
+  JoinAttemptFailed,
+  CoordinatorStartFailed,
+  CheckingForCoordinator,
+  LeaveFailed,
+  DeviceDidNotRespond,
+  UnsecuredKeyReceived,
+  KeyNotReceived,
+  InvalidLinkKey,
+  Scanning,
+  Other(u8)
+}
+
+impl From<u8> for AssociationIndication {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => AssociationIndication::Associated,
+      0x21 => AssociationIndication::NoPansFound,
+      0x22 => AssociationIndication::NoValidPansFound,
+      0x23 => AssociationIndication::JoiningNotAllowed,
+      0x24 => AssociationIndication::NoJoinableBeacons,
+      0x25 => AssociationIndication::UnexpectedState,
+      0x27 => AssociationIndication::JoinAttemptFailed,
+      0x2A => AssociationIndication::CoordinatorStartFailed,
+      0x2B => AssociationIndication::CheckingForCoordinator,
+      0x2C => AssociationIndication::LeaveFailed,
+      0xAB => AssociationIndication::DeviceDidNotRespond,
+      0xAC => AssociationIndication::UnsecuredKeyReceived,
+      0xAD => AssociationIndication::KeyNotReceived,
+      0xAF => AssociationIndication::InvalidLinkKey,
+      0xFF => AssociationIndication::Scanning,
+      _ => AssociationIndication::Other(value)
+    }
+  }
+}
+
+impl From<AssociationIndication> for u8 {
+  fn from(value: AssociationIndication) -> u8 {
+    match value {
+      AssociationIndication::Associated => 0x00,
+      AssociationIndication::NoPansFound => 0x21,
+      AssociationIndication::NoValidPansFound => 0x22,
+      AssociationIndication::JoiningNotAllowed => 0x23,
+      AssociationIndication::NoJoinableBeacons => 0x24,
+      AssociationIndication::UnexpectedState => 0x25,
+      AssociationIndication::JoinAttemptFailed => 0x27,
+      AssociationIndication::CoordinatorStartFailed => 0x2A,
+      AssociationIndication::CheckingForCoordinator => 0x2B,
+      AssociationIndication::LeaveFailed => 0x2C,
+      AssociationIndication::DeviceDidNotRespond => 0xAB,
+      AssociationIndication::UnsecuredKeyReceived => 0xAC,
+      AssociationIndication::KeyNotReceived => 0xAD,
+      AssociationIndication::InvalidLinkKey => 0xAF,
+      AssociationIndication::Scanning => 0xFF,
+      AssociationIndication::Other(value) => value
+    }
+  }
+}
+
+/// Reads a number of at most `max_length` bytes, MSB first.
+fn decode_number(data: &[u8], max_length: usize) -> Result<u64, ResponseError> {
+  if data.is_empty() || data.len() > max_length { return Err(ResponseError::SizeIncorrectError); }
+
+  Ok(data.iter().fold(0, |value, byte| (value << 8) | *byte as u64))
+}
+
+fn decode_str(data: &[u8]) -> Result<&str, ResponseError> {
+  core::str::from_utf8(data).map_err(|_| ResponseError::EncodingError)
+}
+
+/// Writes the lowest `length` bytes of `value` to `buffer`, MSB first.
+fn encode_number(value: u64, length: usize, buffer: &mut [u8]) -> Result<&[u8], BufferSizeError> {
+  if buffer.len() < length { return Err(BufferSizeError); }
+
+  for (index, byte) in buffer[0..length].iter_mut().enumerate() {
+    *byte = (value >> (8 * (length - index - 1))) as u8;
+  }
+
+  Ok(&buffer[0..length])
+}
+
+#[test]
+fn test_set_pan_id() {
+  use super::Command;
+
+  let mut scratch: [u8; 8] = [0; 8];
+  let mut buffer: [u8; 12] = [0; 12];
+  let value = Value::PanId(0x1234);
+
+  let command = value.to_command(0x01, &mut scratch).unwrap();
+  assert_eq!(
+    command.fill_buffer(&mut buffer).unwrap(),
+    [0x08, 0x01, b'I', b'D', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34]
+  );
+}
+
+#[test]
+fn test_set_node_identifier() {
+  use super::Command;
+
+  let mut buffer: [u8; 10] = [0; 10];
+  let value = Value::NodeIdentifier("ROUTER");
+
+  let command = value.to_command(0x01, &mut []).unwrap();
+  assert_eq!(
+    command.fill_buffer(&mut buffer).unwrap(),
+    [0x08, 0x01, b'N', b'I', b'R', b'O', b'U', b'T', b'E', b'R']
+  );
+}
+
+#[test]
+fn test_execute_command() {
+  use super::Command;
+
+  let mut buffer: [u8; 4] = [0; 4];
+  let command = Value::ApplyChanges.to_command(0x01, &mut []).unwrap();
+
+  assert_eq!(command.fill_buffer(&mut buffer).unwrap(), [0x08, 0x01, b'A', b'C']);
+}
+
+#[test]
+fn test_set_read_only() {
+  let mut scratch: [u8; 10] = [0; 10];
+
+  assert_eq!(
+    Value::SerialNumberLow(0x4052_2BAA).to_command(0x01, &mut scratch).err(),
+    Some(SetValueError::ReadOnly)
+  );
+  assert_eq!(
+    Value::DestinationAddress { network_address: 0x1234, address: 0x0013_A200_4052_2BAA }
+      .to_command(0x01, &mut scratch).err(),
+    Some(SetValueError::ReadOnly)
+  );
+  assert_eq!(
+    Value::PanId(0x1234).to_command(0x01, &mut scratch[..4]).err(),
+    Some(SetValueError::BufferSize(BufferSizeError))
+  );
+}
+
+#[test]
+fn test_decode_responses() {
+  use crate::responses::Response;
+
+  let buffer: [u8; 9] = [0x88, 0x01, b'S', b'L', 0x00, 0x40, 0x52, 0x2B, 0xAA];
+  let response = AtCommandResponse::parse(&buffer).unwrap();
+  assert_eq!(Value::try_from(&response), Ok(Value::SerialNumberLow(0x4052_2BAA)));
+
+  let buffer: [u8; 6] = [0x88, 0x01, b'B', b'D', 0x00, 0x07];
+  let response = AtCommandResponse::parse(&buffer).unwrap();
+  assert_eq!(Value::try_from(&response), Ok(Value::BaudRate(BaudRate::B115200)));
+
+  let buffer: [u8; 6] = [0x88, 0x01, b'A', b'I', 0x00, 0x23];
+  let response = AtCommandResponse::parse(&buffer).unwrap();
+  assert_eq!(
+    Value::try_from(&response),
+    Ok(Value::AssociationIndication(AssociationIndication::JoiningNotAllowed))
+  );
+
+  let buffer: [u8; 8] = [0x88, 0x01, b'N', b'I', 0x00, b'C', b'O', b'O'];
+  let response = AtCommandResponse::parse(&buffer).unwrap();
+  assert_eq!(Value::try_from(&response), Ok(Value::NodeIdentifier("COO")));
+}
+
+#[test]
+fn test_decode_remote_response() {
+  use crate::responses::Response;
+
+  let buffer: [u8; 16] = [
+    0x97, 0x01,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    b'P', b'L',
+    0x00,
+    0x04
+  ];
+  let response = RemoteAtCommandResponse::parse(&buffer).unwrap();
+  assert_eq!(Value::try_from(&response), Ok(Value::PowerLevel(PowerLevel::Highest)));
+}
+
+#[test]
+fn test_decode_node_discovery() {
+  use crate::responses::{ Response, DeviceType };
+
+  let buffer: [u8; 26] = [
+    0x88, 0x01, b'N', b'D', 0x00,
+    0x12, 0x34,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27,
+    b'N', b'1', 0x00,
+    0xFF, 0xFE,
+    0x01,
+    0x00,
+    0xC1, 0x05,
+    0x10, 0x1E
+  ];
+
+  // The manufacturer ID is cut short
+  let response = AtCommandResponse::parse(&buffer[..25]).unwrap();
+  assert_eq!(Value::try_from(&response), Err(ResponseError::SizeIncorrectError));
+
+  let response = AtCommandResponse::parse(&buffer).unwrap();
+  match Value::try_from(&response) {
+    Ok(Value::DiscoveredNode(node)) => {
+      assert_eq!(node.address, 0x0013_A200_400A_0127);
+      assert_eq!(node.node_identifier, "N1");
+      assert_eq!(node.device_type, DeviceType::Router);
+    },
+    _ => panic!("expected a discovered node")
+  }
+}
+
+#[test]
+fn test_decode_short_numbers() {
+  // The XBee drops leading zeros from some numeric registers
+  assert_eq!(Register::PanId.decode(&[0x12, 0x34]), Ok(Value::PanId(0x1234)));
+  assert_eq!(Register::PanId.decode(&[]), Err(ResponseError::SizeIncorrectError));
+  assert_eq!(Register::Channel.decode(&[0x0B, 0x00]), Err(ResponseError::SizeIncorrectError));
+}
+
+#[test]
+fn test_decode_errors() {
+  assert_eq!(Register::PowerLevel.decode(&[0x05]), Err(ResponseError::EnumComparisonError));
+  assert_eq!(Register::NodeIdentifier.decode(&[0xFF, 0xFE]), Err(ResponseError::EncodingError));
+  assert_eq!(Register::try_from(*b"ZZ"), Err(ResponseError::EnumComparisonError));
+}
+
+#[test]
+fn test_round_trip_values() {
+  let values = [
+    Value::PanId(0x0123_4567_89AB_CDEF),
+    Value::NetworkAddress(0x7D84),
+    Value::PowerLevel(PowerLevel::High),
+    Value::ApiEnable(ApiEnable::ApiEscaped),
+    Value::SleepMode(SleepMode::CyclicSleep),
+    Value::SleepPeriod(0x0AF0),
+    Value::EncryptionEnable(true),
+    Value::DestinationAddress { network_address: 0x1234, address: 0x0013_A200_4052_2BAA }
+  ];
+
+  for value in values.iter() {
+    let mut scratch: [u8; 10] = [0; 10];
+    let parameter = value.fill_parameter(&mut scratch).unwrap();
+    assert_eq!(value.register().decode(parameter), Ok(*value));
+  }
+}
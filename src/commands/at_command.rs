@@ -1,36 +1,98 @@
-use super::{ Command, BufferSizeError };
+use super::{ Command, ByteWriter };
 
 const COMMAND_ID: u8 = 0x08;
+const QUEUE_COMMAND_ID: u8 = 0x09;
+const APPLY_CHANGES: [u8; 2] = [b'A', b'C'];
 
-pub struct AtCommand {
+pub struct AtCommand<'a> {
   frame_id: u8,
   at_command: [u8; 2],
-  parameter_value: Option<u8>,
+  parameter_value: Option<&'a [u8]>,
 }
 
-impl AtCommand {
-  pub fn new(frame_id: u8, at_command: [u8; 2], parameter_value: Option<u8>) -> Self {
+impl <'a> AtCommand<'a> {
+  /// Builds an AT command. Without a `parameter_value` the register is
+  /// queried, otherwise it is set to the given bytes, MSB first.
+  pub fn new(frame_id: u8, at_command: [u8; 2], parameter_value: Option<&'a [u8]>) -> Self {
     AtCommand { frame_id, at_command, parameter_value }
   }
+
+  fn encode_with_id<W: ByteWriter>(&self, command_id: u8, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(command_id)?;
+    writer.write_byte(self.frame_id)?;
+    writer.write_bytes(&self.at_command)?;
+    writer.write_bytes(self.parameter_value.unwrap_or(&[]))
+  }
+}
+
+impl <'a> Command for AtCommand<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    self.encode_with_id(COMMAND_ID, writer)
+  }
+}
+
+/// An AT command whose new value is queued instead of applied. Queued
+/// values take effect together once an `AC` command, or any regular
+/// `AtCommand`, is sent.
+pub struct AtCommandQueue<'a> {
+  command: AtCommand<'a>
+}
+
+impl <'a> AtCommandQueue<'a> {
+  pub fn new(frame_id: u8, at_command: [u8; 2], parameter_value: Option<&'a [u8]>) -> Self {
+    AtCommandQueue { command: AtCommand::new(frame_id, at_command, parameter_value) }
+  }
 }
 
-impl Command for AtCommand {
-  fn fill_buffer<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a [u8], BufferSizeError> {
-    if buffer.len() < 4 { return Err(BufferSizeError); }
+impl <'a> Command for AtCommandQueue<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    self.command.encode_with_id(QUEUE_COMMAND_ID, writer)
+  }
+}
+
+/// One of the commands produced by an `AtCommandBatch`.
+pub enum BatchCommand<'a> {
+  Queue(AtCommandQueue<'a>),
+  Apply(AtCommand<'static>)
+}
+
+impl <'a> Command for BatchCommand<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    match self {
+      BatchCommand::Queue(command) => command.encode(writer),
+      BatchCommand::Apply(command) => command.encode(writer)
+    }
+  }
+}
+
+/// Turns a list of `(command, value)` pairs into queued commands followed
+/// by a single `AC`, so that all of the changes are applied at once. Every
+/// command uses the same frame id.
+pub struct AtCommandBatch<'a> {
+  frame_id: u8,
+  settings: &'a [([u8; 2], &'a [u8])],
+  index: usize
+}
+
+impl <'a> AtCommandBatch<'a> {
+  pub fn new(frame_id: u8, settings: &'a [([u8; 2], &'a [u8])]) -> Self {
+    AtCommandBatch { frame_id, settings, index: 0 }
+  }
+}
 
-    buffer[0] = COMMAND_ID;
-    buffer[1] = self.frame_id;
-    buffer[2] = self.at_command[0];
-    buffer[3] = self.at_command[1];
+impl <'a> Iterator for AtCommandBatch<'a> {
+  type Item = BatchCommand<'a>;
 
-    match self.parameter_value {
-      Some(value) => {
-        if buffer.len() < 5 { return Err(BufferSizeError); }
+  fn next(&mut self) -> Option<BatchCommand<'a>> {
+    let index = self.index;
+    if index > self.settings.len() { return None; }
+    self.index += 1;
 
-        buffer[4] = value;
-        Ok(&buffer[0..5])
+    match self.settings.get(index) {
+      Some((at_command, value)) => {
+        Some(BatchCommand::Queue(AtCommandQueue::new(self.frame_id, *at_command, Some(value))))
       },
-      _ => Ok(&buffer[0..4])
+      None => Some(BatchCommand::Apply(AtCommand::new(self.frame_id, APPLY_CHANGES, None)))
     }
   }
 }
@@ -47,8 +109,61 @@ fn test_empty_at_command() {
 #[test]
 fn test_filled_at_command() {
   let mut buffer: [u8; 5] = unsafe { core::mem::zeroed() };
-  let at_command = AtCommand::new(0x52, [b'N', b'J'], Some(b'K'));
+  let at_command = AtCommand::new(0x52, [b'N', b'J'], Some(b"K"));
 
   let command = at_command.fill_buffer(&mut buffer[..]);
   assert_eq!(command.unwrap(), [0x08, 0x52, b'N', b'J', b'K']);
 }
+
+#[test]
+fn test_multi_byte_at_command() {
+  let mut buffer: [u8; 12] = unsafe { core::mem::zeroed() };
+  let pan_id: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34];
+  let at_command = AtCommand::new(0x01, [b'I', b'D'], Some(&pan_id));
+
+  let command = at_command.fill_buffer(&mut buffer[..]);
+  assert_eq!(
+    command.unwrap(),
+    [0x08, 0x01, b'I', b'D', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34]
+  );
+}
+
+#[test]
+fn test_at_command_buffer_too_small() {
+  let mut buffer: [u8; 6] = unsafe { core::mem::zeroed() };
+  let at_command = AtCommand::new(0x01, [b'N', b'I'], Some(b"ROUTER"));
+
+  assert!(at_command.fill_buffer(&mut buffer[..]).is_err());
+}
+
+#[test]
+fn test_queued_at_command() {
+  let mut buffer: [u8; 6] = unsafe { core::mem::zeroed() };
+  let at_command = AtCommandQueue::new(0x01, [b'B', b'D'], Some(&[0x07]));
+
+  let command = at_command.fill_buffer(&mut buffer[..]);
+  assert_eq!(command.unwrap(), [0x09, 0x01, b'B', b'D', 0x07]);
+}
+
+#[test]
+fn test_at_command_batch() {
+  let mut buffer: [u8; 8] = unsafe { core::mem::zeroed() };
+  let settings: [([u8; 2], &[u8]); 2] = [
+    ([b'C', b'E'], &[0x01]),
+    ([b'I', b'D'], &[0x12, 0x34])
+  ];
+
+  let mut batch = AtCommandBatch::new(0x05, &settings);
+
+  let command = batch.next().unwrap();
+  assert_eq!(command.fill_buffer(&mut buffer[..]).unwrap(), [0x09, 0x05, b'C', b'E', 0x01]);
+
+  let command = batch.next().unwrap();
+  assert_eq!(command.fill_buffer(&mut buffer[..]).unwrap(), [0x09, 0x05, b'I', b'D', 0x12, 0x34]);
+
+  let command = batch.next().unwrap();
+  assert_eq!(command.fill_buffer(&mut buffer[..]).unwrap(), [0x08, 0x05, b'A', b'C']);
+
+  assert!(batch.next().is_none());
+  assert!(batch.next().is_none());
+}
@@ -0,0 +1,77 @@
+use super::{ Command, ByteWriter, BufferSizeError };
+
+const COMMAND_ID: u8 = 0x21;
+const MAXIMUM_HOPS: usize = 0xFF;
+
+/// Stores a source route in the XBee so that the next transmission to
+/// `destination` follows it. The XBee never answers this frame, so its
+/// frame id is always zero.
+pub struct CreateSourceRoute<'a> {
+  destination: u64,
+  network_address: u16,
+  hops: &'a [u16]
+}
+
+impl <'a> CreateSourceRoute<'a> {
+  /// Builds the frame from the 16-bit addresses of the intermediate hops,
+  /// starting with the neighbor of the destination. This is the same order
+  /// a `RouteRecordIndicator` lists them in. Fails if there are more than
+  /// 255 hops, which the frame cannot hold.
+  pub fn new(destination: u64, network_address: u16, hops: &'a [u16]) -> Result<Self, BufferSizeError> {
+    if hops.len() > MAXIMUM_HOPS { return Err(BufferSizeError); }
+
+    Ok(CreateSourceRoute { destination, network_address, hops })
+  }
+}
+
+impl <'a> Command for CreateSourceRoute<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(COMMAND_ID)?;
+    writer.write_byte(0x00)?;
+    writer.write_u64(self.destination)?;
+    writer.write_u16(self.network_address)?;
+
+    // No route options are defined
+    writer.write_byte(0x00)?;
+    writer.write_byte(self.hops.len() as u8)?;
+
+    for hop in self.hops {
+      writer.write_u16(*hop)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_create_source_route() {
+  let mut buffer: [u8; 20] = unsafe { core::mem::zeroed() };
+  let hops: [u16; 3] = [0xAABB, 0xCCDD, 0xEEFF];
+  let command = CreateSourceRoute::new(0x0013_A200_4052_2BAA, 0x3344, &hops).unwrap();
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x21,
+      0x00,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+      0x33, 0x44,
+      0x00,
+      0x03,
+      0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF
+    ]
+  );
+}
+
+#[test]
+fn test_create_source_route_failure() {
+  let mut buffer: [u8; 19] = unsafe { core::mem::zeroed() };
+  let hops: [u16; 3] = [0xAABB, 0xCCDD, 0xEEFF];
+  let command = CreateSourceRoute::new(0x0013_A200_4052_2BAA, 0x3344, &hops).unwrap();
+
+  assert!(command.fill_buffer(&mut buffer[..]).is_err());
+
+  let hops: [u16; 256] = [0; 256];
+  assert_eq!(CreateSourceRoute::new(0x0013_A200_4052_2BAA, 0x3344, &hops).err(), Some(BufferSizeError));
+  assert!(CreateSourceRoute::new(0x0013_A200_4052_2BAA, 0x3344, &hops[..255]).is_ok());
+}
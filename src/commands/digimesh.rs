@@ -0,0 +1,63 @@
+/// How a DigiMesh transmission is delivered. It is encoded in bits 6 and 7
+/// of the transmit options, which Zigbee firmware uses for other flags.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeliveryMethod {
+  /// Uses the default set with the `TO` register.
+  Default = 0x00,
+  /// Sends directly to a neighbor, without routing through the mesh.
+  PointMultipoint = 0x40,
+  /// Sends a directed broadcast that every node repeats.
+  Repeater = 0x80,
+  /// Routes the packet through the mesh.
+  DigiMesh = 0xC0
+}
+
+/// The transmit options of a DigiMesh transmit request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransmitOptions {
+  pub delivery_method: DeliveryMethod,
+  pub disable_ack: bool,
+  pub disable_route_discovery: bool,
+  /// Sends a Route Information frame back when an acknowledgement fails.
+  pub nack: bool,
+  /// Sends a Route Information frame back for every hop of the route.
+  pub trace_route: bool
+}
+
+impl Default for TransmitOptions {
+  fn default() -> Self {
+    TransmitOptions {
+      delivery_method: DeliveryMethod::Default,
+      disable_ack: false,
+      disable_route_discovery: false,
+      nack: false,
+      trace_route: false
+    }
+  }
+}
+
+impl TransmitOptions {
+  /// The options with just the delivery method set.
+  pub fn new(delivery_method: DeliveryMethod) -> Self {
+    TransmitOptions { delivery_method, ..Default::default() }
+  }
+
+  /// Encodes the options for the transmit options byte.
+  pub fn to_byte(self) -> u8 {
+    let mut options = self.delivery_method as u8;
+    if self.disable_ack { options |= 0x01; }
+    if self.disable_route_discovery { options |= 0x02; }
+    if self.nack { options |= 0x04; }
+    if self.trace_route { options |= 0x08; }
+    options
+  }
+}
+
+#[test]
+fn test_transmit_options_to_byte() {
+  assert_eq!(TransmitOptions::default().to_byte(), 0x00);
+  assert_eq!(TransmitOptions::new(DeliveryMethod::Repeater).to_byte(), 0x80);
+
+  let options = TransmitOptions { nack: true, trace_route: true, ..TransmitOptions::new(DeliveryMethod::DigiMesh) };
+  assert_eq!(options.to_byte(), 0xCC);
+}
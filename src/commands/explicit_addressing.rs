@@ -0,0 +1,159 @@
+use super::{ Command, ByteWriter };
+use super::transmit_settings::{ TransmitSettings, TransmitBuilder };
+
+const COMMAND_ID: u8 = 0x11;
+
+/// Sets the options of an `ExplicitAddressingCommand`, created with
+/// `ExplicitAddressingCommand::builder`.
+pub type ExplicitAddressingBuilder<'a> = TransmitBuilder<ExplicitAddressingCommand<'a>>;
+
+/// A transmit request that also sets the application layer addressing:
+/// the source and destination endpoints, the cluster ID and the profile ID.
+pub struct ExplicitAddressingCommand<'a> {
+  frame_id: u8,
+  settings: TransmitSettings,
+  source_endpoint: u8,
+  destination_endpoint: u8,
+  cluster_id: u16,
+  profile_id: u16,
+  data: &'a [u8]
+}
+
+impl <'a> ExplicitAddressingCommand<'a> {
+  pub fn new(
+    frame_id: u8,
+    destination: u64,
+    source_endpoint: u8,
+    destination_endpoint: u8,
+    cluster_id: u16,
+    profile_id: u16,
+    data: &'a [u8]
+  ) -> Self {
+    Self {
+      frame_id,
+      settings: TransmitSettings::new(destination),
+      source_endpoint,
+      destination_endpoint,
+      cluster_id,
+      profile_id,
+      data
+    }
+  }
+
+  /// Starts a transmission whose options are set one by one.
+  pub fn builder(
+    frame_id: u8,
+    destination: u64,
+    source_endpoint: u8,
+    destination_endpoint: u8,
+    cluster_id: u16,
+    profile_id: u16,
+    data: &'a [u8]
+  ) -> ExplicitAddressingBuilder<'a> {
+    TransmitBuilder::new(
+      Self::new(frame_id, destination, source_endpoint, destination_endpoint, cluster_id, profile_id, data),
+      |command| &mut command.settings
+    )
+  }
+}
+
+impl <'a> Command for ExplicitAddressingCommand<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(COMMAND_ID)?;
+    writer.write_byte(self.frame_id)?;
+    self.settings.encode_addresses(writer)?;
+
+    // Application layer addressing
+    writer.write_byte(self.source_endpoint)?;
+    writer.write_byte(self.destination_endpoint)?;
+    writer.write_u16(self.cluster_id)?;
+    writer.write_u16(self.profile_id)?;
+
+    self.settings.encode_options(writer)?;
+    writer.write_bytes(self.data)
+  }
+}
+
+#[test]
+fn test_explicit_addressing_success() {
+  let mut buffer: [u8; 22] = unsafe { core::mem::zeroed() };
+  let data: [u8; 2] = [0x00, 0x01];
+
+  // A ZDO Active Endpoints Request to a Home Automation device
+  let command = ExplicitAddressingCommand::new(
+    0x01, 0x0013_A200_4052_2BAA, 0x00, 0x00, 0x0005, 0x0000, &data[..]
+  );
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x11,
+      0x01,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+      0xFF, 0xFE,
+      0x00,
+      0x00,
+      0x00, 0x05,
+      0x00, 0x00,
+      0x00,
+      0x00,
+      0x00, 0x01
+    ]
+  );
+}
+
+#[test]
+fn test_explicit_addressing_builder() {
+  use super::transmit_settings::BROADCAST_DESTINATION;
+  use super::TransmitSettingsError;
+
+  let mut buffer: [u8; 22] = unsafe { core::mem::zeroed() };
+  let data: [u8; 2] = [0x00, 0x01];
+
+  let command = ExplicitAddressingCommand::builder(
+    0x01, 0x0013_A200_4052_2BAA, 0xE8, 0x01, 0x0006, 0x0104, &data[..]
+  )
+    .network_address(0x7D84)
+    .radius(0x03)
+    .disable_retries(true)
+    .enable_encryption(true)
+    .use_extended_timeout(true)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x11,
+      0x01,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+      0x7D, 0x84,
+      0xE8,
+      0x01,
+      0x00, 0x06,
+      0x01, 0x04,
+      0x03,
+      0x61,
+      0x00, 0x01
+    ]
+  );
+
+  let result = ExplicitAddressingCommand::builder(
+    0x01, BROADCAST_DESTINATION, 0xE8, 0xE8, 0x0011, 0xC105, &data[..]
+  )
+    .enable_encryption(true)
+    .build();
+
+  assert_eq!(result.err(), Some(TransmitSettingsError::EncryptedBroadcast));
+}
+
+#[test]
+fn test_explicit_addressing_failure() {
+  let mut buffer: [u8; 21] = unsafe { core::mem::zeroed() };
+  let data: [u8; 2] = [0x00, 0x01];
+  let command = ExplicitAddressingCommand::new(
+    0x01, 0x0013_A200_4052_2BAA, 0xE8, 0x01, 0x0006, 0x0104, &data[..]
+  );
+
+  assert!(command.fill_buffer(&mut buffer[..]).is_err());
+}
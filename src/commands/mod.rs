@@ -1,12 +1,110 @@
 pub mod at_command;
 pub mod transmit_request;
+pub mod transmit_settings;
+pub mod remote_at_command;
+pub mod explicit_addressing;
+pub mod create_source_route;
+pub mod register_joining_device;
+pub mod at;
+#[cfg(feature = "ieee802154")]
+pub mod tx_request;
+#[cfg(feature = "digimesh")]
+pub mod digimesh;
 
-pub use transmit_request::TransmitRequestCommand;
-pub use at_command::AtCommand;
+pub use transmit_request::{ TransmitRequestCommand, TransmitRequestBuilder };
+pub use transmit_settings::{ TransmitBuilder, TransmitSettingsError };
+pub use at_command::{ AtCommand, AtCommandQueue, AtCommandBatch, BatchCommand };
+pub use remote_at_command::RemoteAtCommand;
+pub use explicit_addressing::{ ExplicitAddressingCommand, ExplicitAddressingBuilder };
+pub use create_source_route::CreateSourceRoute;
+pub use register_joining_device::RegisterJoiningDevice;
+#[cfg(feature = "ieee802154")]
+pub use tx_request::{ TxRequest64, TxRequest16 };
+#[cfg(feature = "digimesh")]
+pub use digimesh::{ DeliveryMethod, TransmitOptions };
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct BufferSizeError;
 
+/// A sink that commands are encoded into one byte at a time, such as a
+/// serial port or a slice.
+pub trait ByteWriter {
+  type Error;
+
+  fn write_byte(&mut self, byte: u8) -> Result<(), Self::Error>;
+
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+    for byte in bytes {
+      self.write_byte(*byte)?;
+    }
+
+    Ok(())
+  }
+
+  /// Writes `value` MSB first.
+  fn write_u16(&mut self, value: u16) -> Result<(), Self::Error> {
+    self.write_bytes(&value.to_be_bytes())
+  }
+
+  /// Writes `value` MSB first.
+  fn write_u64(&mut self, value: u64) -> Result<(), Self::Error> {
+    self.write_bytes(&value.to_be_bytes())
+  }
+}
+
 pub trait Command {
-  fn fill_buffer<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a [u8], BufferSizeError>;
+  /// Writes the frame data of the command, starting with its frame type,
+  /// without needing a buffer for the whole command.
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error>;
+
+  /// The number of bytes `encode` writes.
+  fn encoded_len(&self) -> usize {
+    let mut counter = ByteCounter { count: 0 };
+
+    match self.encode(&mut counter) {
+      Ok(()) => counter.count,
+      Err(never) => match never {}
+    }
+  }
+
+  /// Encodes the command into the start of `buffer` and returns the filled part.
+  fn fill_buffer<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a [u8], BufferSizeError> {
+    let mut writer = SliceWriter { buffer: &mut *buffer, position: 0 };
+    self.encode(&mut writer)?;
+
+    let length = writer.position;
+    Ok(&buffer[0..length])
+  }
+}
+
+/// Counts the bytes written to it, for `Command::encoded_len`.
+struct ByteCounter {
+  count: usize
+}
+
+impl ByteWriter for ByteCounter {
+  type Error = core::convert::Infallible;
+
+  fn write_byte(&mut self, _byte: u8) -> Result<(), Self::Error> {
+    self.count += 1;
+    Ok(())
+  }
+}
+
+/// Fills a slice from its start, for `Command::fill_buffer`.
+pub(crate) struct SliceWriter<'a> {
+  pub(crate) buffer: &'a mut [u8],
+  pub(crate) position: usize
+}
+
+impl <'a> ByteWriter for SliceWriter<'a> {
+  type Error = BufferSizeError;
+
+  fn write_byte(&mut self, byte: u8) -> Result<(), BufferSizeError> {
+    let slot = self.buffer.get_mut(self.position).ok_or(BufferSizeError)?;
+    *slot = byte;
+    self.position += 1;
+
+    Ok(())
+  }
 }
@@ -0,0 +1,69 @@
+use super::{ Command, ByteWriter };
+#[cfg(test)]
+use super::BufferSizeError;
+
+const COMMAND_ID: u8 = 0x24;
+
+/// Registers a device on the trust center so that it is allowed to join
+/// the network. The key is either a preconfigured link key or an install
+/// code including its CRC; see `security::validate_install_code`.
+pub struct RegisterJoiningDevice<'a> {
+  frame_id: u8,
+  destination: u64,
+  install_code: bool,
+  key: &'a [u8]
+}
+
+impl <'a> RegisterJoiningDevice<'a> {
+  /// Registers `destination` with a link key of up to 16 bytes.
+  pub fn with_link_key(frame_id: u8, destination: u64, link_key: &'a [u8]) -> Self {
+    RegisterJoiningDevice { frame_id, destination, install_code: false, key: link_key }
+  }
+
+  /// Registers `destination` with an install code, from which the XBee
+  /// derives the link key itself.
+  pub fn with_install_code(frame_id: u8, destination: u64, install_code: &'a [u8]) -> Self {
+    RegisterJoiningDevice { frame_id, destination, install_code: true, key: install_code }
+  }
+}
+
+impl <'a> Command for RegisterJoiningDevice<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(COMMAND_ID)?;
+    writer.write_byte(self.frame_id)?;
+    writer.write_u64(self.destination)?;
+
+    // The 16-bit address is reserved and must be 0xFFFE
+    writer.write_u16(0xFFFE)?;
+    writer.write_byte(if self.install_code { 0x01 } else { 0x00 })?;
+    writer.write_bytes(self.key)
+  }
+}
+
+#[test]
+fn test_register_joining_device_install_code() {
+  let mut buffer: [u8; 32] = unsafe { core::mem::zeroed() };
+  let install_code: [u8; 10] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A];
+  let command = RegisterJoiningDevice::with_install_code(0x01, 0x0013_A200_4052_2BAA, &install_code);
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x24,
+      0x01,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+      0xFF, 0xFE,
+      0x01,
+      0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A
+    ]
+  );
+}
+
+#[test]
+fn test_register_joining_device_buffer_too_small() {
+  let mut buffer: [u8; 16] = unsafe { core::mem::zeroed() };
+  let link_key: [u8; 16] = [0x5A; 16];
+  let command = RegisterJoiningDevice::with_link_key(0x01, 0x0013_A200_4052_2BAA, &link_key);
+
+  assert_eq!(command.fill_buffer(&mut buffer[..]), Err(BufferSizeError));
+}
@@ -0,0 +1,77 @@
+use super::{ Command, ByteWriter };
+
+const COMMAND_ID: u8 = 0x17;
+const APPLY_CHANGES: u8 = 0x02;
+
+/// Queries or sets an AT command register on a remote device.
+pub struct RemoteAtCommand<'a> {
+  frame_id: u8,
+  destination: u64,
+  network_address: u16,
+  apply_changes: bool,
+  at_command: [u8; 2],
+  parameter_value: Option<&'a [u8]>,
+}
+
+impl <'a> RemoteAtCommand<'a> {
+  /// Builds a remote AT command for the device at `destination`. Use
+  /// 0xFFFE as the `network_address` if it is not known. Unless
+  /// `apply_changes` is set, a changed register only takes effect after
+  /// an `AC` command is sent to the device.
+  pub fn new(
+    frame_id: u8,
+    destination: u64,
+    network_address: u16,
+    at_command: [u8; 2],
+    parameter_value: Option<&'a [u8]>,
+    apply_changes: bool
+  ) -> Self {
+    RemoteAtCommand { frame_id, destination, network_address, apply_changes, at_command, parameter_value }
+  }
+}
+
+impl <'a> Command for RemoteAtCommand<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(COMMAND_ID)?;
+    writer.write_byte(self.frame_id)?;
+    writer.write_u64(self.destination)?;
+    writer.write_u16(self.network_address)?;
+    writer.write_byte(if self.apply_changes { APPLY_CHANGES } else { 0x00 })?;
+    writer.write_bytes(&self.at_command)?;
+    writer.write_bytes(self.parameter_value.unwrap_or(&[]))
+  }
+}
+
+#[test]
+fn test_remote_at_command_query() {
+  let mut buffer: [u8; 15] = unsafe { core::mem::zeroed() };
+  let command = RemoteAtCommand::new(0x01, 0x0013_A200_4052_2BAA, 0xFFFE, [b'B', b'H'], None, true);
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [0x17, 0x01, 0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA, 0xFF, 0xFE, 0x02, b'B', b'H']
+  );
+}
+
+#[test]
+fn test_remote_at_command_set() {
+  let mut buffer: [u8; 17] = unsafe { core::mem::zeroed() };
+  let command = RemoteAtCommand::new(
+    0x01, 0x0013_A200_4052_2BAA, 0x7D84, [b'S', b'P'], Some(&[0x01, 0xF4]), false
+  );
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [0x17, 0x01, 0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA, 0x7D, 0x84, 0x00, b'S', b'P', 0x01, 0xF4]
+  );
+}
+
+#[test]
+fn test_remote_at_command_failure() {
+  let mut buffer: [u8; 16] = unsafe { core::mem::zeroed() };
+  let command = RemoteAtCommand::new(
+    0x01, 0x0013_A200_4052_2BAA, 0x7D84, [b'S', b'P'], Some(&[0x01, 0xF4]), false
+  );
+
+  assert!(command.fill_buffer(&mut buffer[..]).is_err());
+}
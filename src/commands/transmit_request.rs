@@ -1,34 +1,24 @@
-use super::{ Command, BufferSizeError };
+use super::{ Command, ByteWriter };
+use super::transmit_settings::{ TransmitSettings, TransmitBuilder, BROADCAST_DESTINATION };
+#[cfg(feature = "digimesh")]
+use super::TransmitOptions;
 
 const COMMAND_ID: u8 = 0x10;
-const BROADCAST_DESTINATION: u64 =   0x0000_0000_0000_FFFF;
 const COORDINATOR_DESTINATION: u64 = 0x0000_0000_0000_0000;
-const BROADCAST_NETWORK_ADDRESS: u16 = 0xFFFE;
-const MAXIMUM_HOPS: u8 = 0x00;
+
+/// Sets the options of a `TransmitRequestCommand`, created with
+/// `TransmitRequestCommand::builder`.
+pub type TransmitRequestBuilder<'a> = TransmitBuilder<TransmitRequestCommand<'a>>;
 
 pub struct TransmitRequestCommand<'a> {
   frame_id: u8,
-  destination: u64,
-  network_address: u16,
-  radius: u8,
-  disable_retries: Option<bool>,
-  enable_encryption: Option<bool>,
-  use_extended_timeout: Option<bool>,
+  settings: TransmitSettings,
   data: &'a [u8]
 }
 
 impl <'a> Default for TransmitRequestCommand<'a> {
   fn default() -> Self {
-    Self {
-      frame_id: 0x00,
-      destination: BROADCAST_DESTINATION,
-      network_address: BROADCAST_NETWORK_ADDRESS,
-      radius: MAXIMUM_HOPS,
-      disable_retries: None,
-      enable_encryption: None,
-      use_extended_timeout: None,
-      data: &[]
-    }
+    Self { frame_id: 0x00, settings: TransmitSettings::new(BROADCAST_DESTINATION), data: &[] }
   }
 }
 
@@ -38,57 +28,33 @@ impl <'a> TransmitRequestCommand <'a> {
   }
 
   pub fn to_destination(frame_id: u8, destination: u64, data: &'a [u8]) -> Self {
-    Self { frame_id, destination, data, ..Default::default() }
+    Self { frame_id, settings: TransmitSettings::new(destination), data }
   }
 
   pub fn to_coordinator(frame_id: u8, data: &'a [u8]) -> Self {
-    Self { frame_id, destination: COORDINATOR_DESTINATION, data, ..Default::default() }
+    Self::to_destination(frame_id, COORDINATOR_DESTINATION, data)
+  }
+
+  /// A transmission on a DigiMesh network, which has no network address
+  /// and uses its own transmit options.
+  #[cfg(feature = "digimesh")]
+  pub fn digimesh(frame_id: u8, destination: u64, options: TransmitOptions, data: &'a [u8]) -> Self {
+    Self { frame_id, settings: TransmitSettings::digimesh(destination, options), data }
+  }
+
+  /// Starts a transmission whose options are set one by one.
+  pub fn builder(frame_id: u8, destination: u64, data: &'a [u8]) -> TransmitRequestBuilder<'a> {
+    TransmitBuilder::new(Self::to_destination(frame_id, destination, data), |command| &mut command.settings)
   }
 }
 
 impl <'a> Command for TransmitRequestCommand <'a> {
-  fn fill_buffer<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b [u8], BufferSizeError> {
-    if buffer.len() < 14 + self.data.len() { return Err(BufferSizeError); }
-
-    buffer[0] = COMMAND_ID;
-    buffer[1] = self.frame_id;
-
-    // Fill the destination address, MSB first
-    buffer[2] = ((self.destination >> 56) & 0xFF) as u8;
-    buffer[3] = ((self.destination >> 48) & 0xFF) as u8;
-    buffer[4] = ((self.destination >> 40) & 0xFF) as u8;
-    buffer[5] = ((self.destination >> 32) & 0xFF) as u8;
-    buffer[6] = ((self.destination >> 24) & 0xFF) as u8;
-    buffer[7] = ((self.destination >> 16) & 0xFF) as u8;
-    buffer[8] = ((self.destination >> 8) & 0xFF) as u8;
-    buffer[9] = (self.destination & 0xFF) as u8;
-
-    // Fill the network address, MSB first
-    buffer[10] = (self.network_address / 256) as u8;
-    buffer[11] = (self.network_address & 0xFF) as u8;
-
-    // Number of hops
-    buffer[12] = self.radius;
-
-    // The options byte
-    buffer[13] = 0x00;
-    if let Some(option) = self.disable_retries {
-      if option { buffer[13] |= 0x01; }
-    }
-
-    if let Some(option) = self.enable_encryption {
-      if option { buffer[13] |= 0x20; }
-    }
-
-    if let Some(option) = self.use_extended_timeout {
-      if option { buffer[13] |= 0x40; }
-    }
-
-    // Fill in data
-    for i in 0..self.data.len() { buffer[14 + i] = self.data[i]; }
-
-    // Return the data
-    Ok(&buffer[0..14 + self.data.len()])
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(COMMAND_ID)?;
+    writer.write_byte(self.frame_id)?;
+    self.settings.encode_addresses(writer)?;
+    self.settings.encode_options(writer)?;
+    writer.write_bytes(self.data)
   }
 }
 
@@ -117,6 +83,96 @@ fn test_transmit_request_success() {
   );
 }
 
+#[cfg(feature = "digimesh")]
+#[test]
+fn test_transmit_request_digimesh() {
+  use super::DeliveryMethod;
+
+  let mut buffer: [u8; 16] = unsafe { core::mem::zeroed() };
+  let options = TransmitOptions { trace_route: true, ..TransmitOptions::new(DeliveryMethod::DigiMesh) };
+  let request = TransmitRequestCommand::digimesh(0x01, 0x0013_A200_400A_0127, options, &[0x54, 0x78]);
+
+  assert_eq!(
+    request.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x10,
+      0x01,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27,
+      0xFF, 0xFE,
+      0x00,
+      0xC8,
+      0x54, 0x78
+    ]
+  );
+}
+
+#[test]
+fn test_transmit_request_builder() {
+  let mut buffer: [u8; 16] = unsafe { core::mem::zeroed() };
+  let request = TransmitRequestCommand::builder(0x01, 0x0013_A200_400A_0127, &[0x54, 0x78])
+    .network_address(0x7D84)
+    .radius(0x02)
+    .enable_encryption(true)
+    .use_extended_timeout(true)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    request.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x10,
+      0x01,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27,
+      0x7D, 0x84,
+      0x02,
+      0x60,
+      0x54, 0x78
+    ]
+  );
+}
+
+#[test]
+fn test_transmit_request_builder_errors() {
+  use super::TransmitSettingsError;
+
+  let request = TransmitRequestCommand::builder(0x01, BROADCAST_DESTINATION, &[])
+    .enable_encryption(true)
+    .build();
+  assert_eq!(request.err(), Some(TransmitSettingsError::EncryptedBroadcast));
+
+  let request = TransmitRequestCommand::builder(0x01, 0x0013_A200_400A_0127, &[])
+    .network_address(0xFFFD)
+    .enable_encryption(true)
+    .build();
+  assert_eq!(request.err(), Some(TransmitSettingsError::EncryptedBroadcast));
+}
+
+#[cfg(feature = "digimesh")]
+#[test]
+fn test_transmit_request_builder_delivery_method() {
+  use super::{ DeliveryMethod, TransmitSettingsError };
+
+  let mut buffer: [u8; 14] = unsafe { core::mem::zeroed() };
+  let request = TransmitRequestCommand::builder(0x01, 0x0013_A200_400A_0127, &[])
+    .delivery_method(DeliveryMethod::PointMultipoint)
+    .disable_retries(true)
+    .build()
+    .unwrap();
+  assert_eq!(request.fill_buffer(&mut buffer[..]).unwrap()[13], 0x41);
+
+  let request = TransmitRequestCommand::builder(0x01, 0x0013_A200_400A_0127, &[])
+    .delivery_method(DeliveryMethod::DigiMesh)
+    .enable_encryption(true)
+    .build();
+  assert_eq!(request.err(), Some(TransmitSettingsError::EncryptedDigiMesh));
+
+  let request = TransmitRequestCommand::builder(0x01, 0x0013_A200_400A_0127, &[])
+    .delivery_method(DeliveryMethod::DigiMesh)
+    .use_extended_timeout(true)
+    .build();
+  assert_eq!(request.err(), Some(TransmitSettingsError::ConflictingDeliveryMethod));
+}
+
 #[test]
 fn test_transmit_request_failure() {
   let mut buffer: [u8; 21] = unsafe { core::mem::zeroed() };
@@ -0,0 +1,173 @@
+use super::ByteWriter;
+#[cfg(feature = "digimesh")]
+use super::{ DeliveryMethod, TransmitOptions };
+
+pub(crate) const BROADCAST_DESTINATION: u64 = 0x0000_0000_0000_FFFF;
+const UNKNOWN_NETWORK_ADDRESS: u16 = 0xFFFE;
+const MAXIMUM_HOPS: u8 = 0x00;
+
+/// The option combinations `TransmitBuilder::build` rejects.
+#[derive(Debug, PartialEq)]
+pub enum TransmitSettingsError {
+  /// APS encryption can only be used on unicast transmissions.
+  EncryptedBroadcast,
+  /// The extended timeout option shares bit 6 with the PointMultipoint and
+  /// DigiMesh delivery methods.
+  #[cfg(feature = "digimesh")]
+  ConflictingDeliveryMethod,
+  /// APS encryption is a Zigbee option, which DigiMesh transmit options
+  /// have no bit for.
+  #[cfg(feature = "digimesh")]
+  EncryptedDigiMesh
+}
+
+/// The addressing and transmit options shared by the Transmit Request and
+/// the Explicit Addressing Command.
+#[derive(Clone, Copy)]
+pub(crate) struct TransmitSettings {
+  destination: u64,
+  network_address: u16,
+  radius: u8,
+  disable_retries: Option<bool>,
+  enable_encryption: Option<bool>,
+  use_extended_timeout: Option<bool>,
+  #[cfg(feature = "digimesh")]
+  digimesh_options: Option<TransmitOptions>
+}
+
+impl TransmitSettings {
+  /// Settings for `destination` with an unknown network address and no
+  /// options set.
+  pub(crate) fn new(destination: u64) -> Self {
+    TransmitSettings {
+      destination,
+      network_address: UNKNOWN_NETWORK_ADDRESS,
+      radius: MAXIMUM_HOPS,
+      disable_retries: None,
+      enable_encryption: None,
+      use_extended_timeout: None,
+      #[cfg(feature = "digimesh")]
+      digimesh_options: None
+    }
+  }
+
+  #[cfg(feature = "digimesh")]
+  pub(crate) fn digimesh(destination: u64, options: TransmitOptions) -> Self {
+    TransmitSettings { digimesh_options: Some(options), ..Self::new(destination) }
+  }
+
+  fn is_broadcast(&self) -> bool {
+    self.destination == BROADCAST_DESTINATION ||
+      (self.network_address >= 0xFFFC && self.network_address != UNKNOWN_NETWORK_ADDRESS)
+  }
+
+  /// Checks that the options can be sent together.
+  fn validate(&self) -> Result<(), TransmitSettingsError> {
+    let encrypted = self.enable_encryption == Some(true);
+
+    if encrypted && self.is_broadcast() {
+      return Err(TransmitSettingsError::EncryptedBroadcast);
+    }
+
+    #[cfg(feature = "digimesh")]
+    if let Some(options) = self.digimesh_options {
+      if encrypted { return Err(TransmitSettingsError::EncryptedDigiMesh); }
+
+      if options.delivery_method as u8 & 0x40 != 0 && self.use_extended_timeout == Some(true) {
+        return Err(TransmitSettingsError::ConflictingDeliveryMethod);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Writes the 64 and 16-bit destination addresses, MSB first.
+  pub(crate) fn encode_addresses<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_u64(self.destination)?;
+    writer.write_u16(self.network_address)
+  }
+
+  /// Writes the radius and the transmit options byte.
+  pub(crate) fn encode_options<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(self.radius)?;
+
+    let mut options = 0x00;
+    if let Some(option) = self.disable_retries {
+      if option { options |= 0x01; }
+    }
+
+    if let Some(option) = self.enable_encryption {
+      if option { options |= 0x20; }
+    }
+
+    if let Some(option) = self.use_extended_timeout {
+      if option { options |= 0x40; }
+    }
+
+    #[cfg(feature = "digimesh")]
+    if let Some(digimesh_options) = self.digimesh_options {
+      options |= digimesh_options.to_byte();
+    }
+
+    writer.write_byte(options)
+  }
+}
+
+/// Sets the transmit options of a command one by one. It is created with
+/// `TransmitRequestCommand::builder` or `ExplicitAddressingCommand::builder`.
+pub struct TransmitBuilder<C> {
+  command: C,
+  settings: fn(&mut C) -> &mut TransmitSettings
+}
+
+impl <C> TransmitBuilder<C> {
+  /// Starts building `command`, whose settings `settings` gives access to.
+  pub(crate) fn new(command: C, settings: fn(&mut C) -> &mut TransmitSettings) -> Self {
+    TransmitBuilder { command, settings }
+  }
+
+  fn settings(&mut self) -> &mut TransmitSettings {
+    (self.settings)(&mut self.command)
+  }
+
+  /// The 16-bit address of the destination, if known. Defaults to 0xFFFE.
+  pub fn network_address(mut self, network_address: u16) -> Self {
+    self.settings().network_address = network_address;
+    self
+  }
+
+  /// The maximum number of hops of a broadcast. Zero uses the network maximum.
+  pub fn radius(mut self, radius: u8) -> Self {
+    self.settings().radius = radius;
+    self
+  }
+
+  pub fn disable_retries(mut self, disable_retries: bool) -> Self {
+    self.settings().disable_retries = Some(disable_retries);
+    self
+  }
+
+  pub fn enable_encryption(mut self, enable_encryption: bool) -> Self {
+    self.settings().enable_encryption = Some(enable_encryption);
+    self
+  }
+
+  pub fn use_extended_timeout(mut self, use_extended_timeout: bool) -> Self {
+    self.settings().use_extended_timeout = Some(use_extended_timeout);
+    self
+  }
+
+  #[cfg(feature = "digimesh")]
+  pub fn delivery_method(mut self, delivery_method: DeliveryMethod) -> Self {
+    let settings = self.settings();
+    let options = settings.digimesh_options.unwrap_or_default();
+    settings.digimesh_options = Some(TransmitOptions { delivery_method, ..options });
+    self
+  }
+
+  /// Checks the combination of options and returns the command.
+  pub fn build(mut self) -> Result<C, TransmitSettingsError> {
+    self.settings().validate()?;
+    Ok(self.command)
+  }
+}
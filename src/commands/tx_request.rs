@@ -0,0 +1,117 @@
+use super::{ Command, ByteWriter };
+#[cfg(test)]
+use super::BufferSizeError;
+
+const TX_REQUEST_64_ID: u8 = 0x00;
+const TX_REQUEST_16_ID: u8 = 0x01;
+const BROADCAST_DESTINATION: u64 = 0x0000_0000_0000_FFFF;
+
+/// Builds the options byte shared by both 802.15.4 transmit requests.
+fn options(disable_ack: bool, broadcast_pan_id: bool) -> u8 {
+  let mut options = 0x00;
+  if disable_ack { options |= 0x01; }
+  if broadcast_pan_id { options |= 0x04; }
+  options
+}
+
+/// An 802.15.4 transmit request to a 64-bit address. The XBee answers it
+/// with a `TxStatus` frame unless the frame id is zero.
+pub struct TxRequest64<'a> {
+  frame_id: u8,
+  destination: u64,
+  disable_ack: bool,
+  broadcast_pan_id: bool,
+  data: &'a [u8]
+}
+
+impl <'a> TxRequest64<'a> {
+  pub fn new(frame_id: u8, destination: u64, data: &'a [u8]) -> Self {
+    TxRequest64 { frame_id, destination, disable_ack: false, broadcast_pan_id: false, data }
+  }
+
+  pub fn broadcast(frame_id: u8, data: &'a [u8]) -> Self {
+    Self::new(frame_id, BROADCAST_DESTINATION, data)
+  }
+
+  /// Sends the packet without asking the destination for a MAC acknowledgement.
+  pub fn disable_ack(self) -> Self {
+    Self { disable_ack: true, ..self }
+  }
+
+  /// Sends the packet to the broadcast PAN ID 0xFFFF.
+  pub fn broadcast_pan_id(self) -> Self {
+    Self { broadcast_pan_id: true, ..self }
+  }
+}
+
+impl <'a> Command for TxRequest64<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(TX_REQUEST_64_ID)?;
+    writer.write_byte(self.frame_id)?;
+    writer.write_u64(self.destination)?;
+    writer.write_byte(options(self.disable_ack, self.broadcast_pan_id))?;
+    writer.write_bytes(self.data)
+  }
+}
+
+/// An 802.15.4 transmit request to a 16-bit address, as configured with
+/// the `MY` register of the destination.
+pub struct TxRequest16<'a> {
+  frame_id: u8,
+  destination: u16,
+  disable_ack: bool,
+  broadcast_pan_id: bool,
+  data: &'a [u8]
+}
+
+impl <'a> TxRequest16<'a> {
+  pub fn new(frame_id: u8, destination: u16, data: &'a [u8]) -> Self {
+    TxRequest16 { frame_id, destination, disable_ack: false, broadcast_pan_id: false, data }
+  }
+
+  /// Sends the packet without asking the destination for a MAC acknowledgement.
+  pub fn disable_ack(self) -> Self {
+    Self { disable_ack: true, ..self }
+  }
+
+  /// Sends the packet to the broadcast PAN ID 0xFFFF.
+  pub fn broadcast_pan_id(self) -> Self {
+    Self { broadcast_pan_id: true, ..self }
+  }
+}
+
+impl <'a> Command for TxRequest16<'a> {
+  fn encode<W: ByteWriter>(&self, writer: &mut W) -> Result<(), W::Error> {
+    writer.write_byte(TX_REQUEST_16_ID)?;
+    writer.write_byte(self.frame_id)?;
+    writer.write_u16(self.destination)?;
+    writer.write_byte(options(self.disable_ack, self.broadcast_pan_id))?;
+    writer.write_bytes(self.data)
+  }
+}
+
+#[test]
+fn test_tx_request_64() {
+  let mut buffer: [u8; 20] = unsafe { core::mem::zeroed() };
+  let command = TxRequest64::new(0x01, 0x0013_A200_4052_2BAA, &[0x52, 0x78]).disable_ack();
+
+  assert_eq!(
+    command.fill_buffer(&mut buffer[..]).unwrap(),
+    [
+      0x00,
+      0x01,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+      0x01,
+      0x52, 0x78
+    ]
+  );
+}
+
+#[test]
+fn test_tx_request_16() {
+  let mut buffer: [u8; 20] = unsafe { core::mem::zeroed() };
+  let command = TxRequest16::new(0x01, 0x1234, &[0x52, 0x78]).broadcast_pan_id();
+
+  assert_eq!(command.fill_buffer(&mut buffer[..]).unwrap(), [0x01, 0x01, 0x12, 0x34, 0x04, 0x52, 0x78]);
+  assert_eq!(command.fill_buffer(&mut buffer[..6]), Err(BufferSizeError));
+}
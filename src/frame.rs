@@ -1,7 +1,40 @@
 use core::num::Wrapping;
-use super::commands::{ Command, BufferSizeError };
+use core::convert::TryFrom;
+use super::commands::{ Command, ByteWriter, BufferSizeError };
+
+/// The start delimiter that begins every frame.
+pub const START_DELIMITER: u8 = 0x7E;
+
+/// The byte used to escape reserved bytes in escaped API mode.
+pub const ESCAPE: u8 = 0x7D;
+
+const XON: u8 = 0x11;
+const XOFF: u8 = 0x13;
+const ESCAPE_MASK: u8 = 0x20;
+
+/// The two API operating modes an XBee can be configured for with the `AP`
+/// command. In `Escaped` mode (AP=2) the bytes 0x7E, 0x7D, 0x11 and 0x13 are
+/// escaped everywhere in a frame except the start delimiter.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ApiMode {
+    #[default]
+    Unescaped,
+    Escaped
+}
+
+/// Returns true if `byte` has to be escaped when sent in escaped API mode.
+pub(crate) fn needs_escape(byte: u8) -> bool {
+    byte == START_DELIMITER || byte == ESCAPE || byte == XON || byte == XOFF
+}
+
+/// Returns the escaped form of a byte that follows an `ESCAPE` byte, or the
+/// original byte given its escaped form.
+pub(crate) fn escape(byte: u8) -> u8 {
+    byte ^ ESCAPE_MASK
+}
 
 /// Representation of a single Frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Frame<'a> {
     pub data: &'a [u8]
 }
@@ -14,12 +47,19 @@ impl <'a> Frame<'a> {
 
     /// Uses a FrameIterator to return an iterator that can be used to
     /// return a serialized message byte by byte.
-    pub fn serialize(&self) -> FrameIterator {
+    pub fn serialize(&self) -> FrameIterator<'_> {
+        self.serialize_with_mode(ApiMode::Unescaped)
+    }
+
+    /// Like `serialize`, but escapes the frame for the given API `mode`.
+    pub fn serialize_with_mode(&self, mode: ApiMode) -> FrameIterator<'_> {
         FrameIterator {
-            frame: &self,
+            frame: self,
+            mode,
             state: FrameIteratorState::Delimiter,
             state_index: 0,
-            checksum: Wrapping(0)
+            checksum: Wrapping(0),
+            escaped: None
         }
     }
 
@@ -32,6 +72,132 @@ impl <'a> Frame<'a> {
             Err(BufferSizeError) => Err(BufferSizeError)
         }
     }
+
+    /// Streams `command` to `writer` as a complete frame, including the
+    /// delimiter, length and checksum, without ever storing it in a buffer.
+    /// The command is encoded twice: once to measure it and once to send it.
+    pub fn encode_command<T: Command, W: ByteWriter>(command: &T, mode: ApiMode, writer: &mut W) -> Result<(), W::Error> {
+        let length = command.encoded_len();
+
+        writer.write_byte(START_DELIMITER)?;
+
+        let mut frame_writer = FrameWriter { writer, mode, checksum: Wrapping(0) };
+        frame_writer.write_escaped((length / 256) as u8)?;
+        frame_writer.write_escaped((length % 256) as u8)?;
+
+        command.encode(&mut frame_writer)?;
+
+        let checksum = 0xFF - frame_writer.checksum.0;
+        frame_writer.write_escaped(checksum)
+    }
+}
+
+/// Escapes the frame data written through it and keeps its checksum.
+struct FrameWriter<'w, W: ByteWriter> {
+    writer: &'w mut W,
+    mode: ApiMode,
+    checksum: Wrapping<u8>
+}
+
+impl <'w, W: ByteWriter> FrameWriter<'w, W> {
+    fn write_escaped(&mut self, byte: u8) -> Result<(), W::Error> {
+        if self.mode == ApiMode::Escaped && needs_escape(byte) {
+            self.writer.write_byte(ESCAPE)?;
+            return self.writer.write_byte(escape(byte));
+        }
+
+        self.writer.write_byte(byte)
+    }
+}
+
+impl <'w, W: ByteWriter> ByteWriter for FrameWriter<'w, W> {
+    type Error = W::Error;
+
+    fn write_byte(&mut self, byte: u8) -> Result<(), W::Error> {
+        self.checksum += Wrapping(byte);
+        self.write_escaped(byte)
+    }
+}
+
+/// An owned frame that holds up to `N` bytes of frame data, so that it can
+/// be stored in a queue or moved between tasks, unlike a borrowed `Frame`.
+#[derive(Debug, Clone)]
+pub struct FrameBuf<const N: usize> {
+    data: [u8; N],
+    len: usize
+}
+
+impl <const N: usize> FrameBuf<N> {
+    /// Builds an empty frame.
+    pub const fn new() -> Self {
+        FrameBuf { data: [0; N], len: 0 }
+    }
+
+    /// Fills a new frame using the data contained in `command`. Fails if
+    /// the command is larger than `N` bytes.
+    pub fn from_command<T: Command>(command: T) -> Result<Self, BufferSizeError> {
+        let mut frame = Self::new();
+        frame.len = command.fill_buffer(&mut frame.data)?.len();
+
+        Ok(frame)
+    }
+
+    /// Borrows the frame, for example to serialize or parse it.
+    pub fn as_frame(&self) -> Frame<'_> {
+        Frame::new(self.data())
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data[0..self.len]
+    }
+
+    /// Replaces the frame data in place with `data`.
+    pub(crate) fn copy_from(&mut self, data: &[u8]) -> Result<(), BufferSizeError> {
+        if data.len() > N { return Err(BufferSizeError); }
+
+        self.data[0..data.len()].copy_from_slice(data);
+        self.len = data.len();
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl <const N: usize> Default for FrameBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <const N: usize> PartialEq for FrameBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data() == other.data()
+    }
+}
+
+impl <'a, const N: usize> TryFrom<Frame<'a>> for FrameBuf<N> {
+    type Error = BufferSizeError;
+
+    /// Copies a borrowed frame. Fails if it is larger than `N` bytes.
+    fn try_from(frame: Frame<'a>) -> Result<Self, BufferSizeError> {
+        let mut owned = Self::new();
+        owned.copy_from(frame.data)?;
+
+        Ok(owned)
+    }
+}
+
+impl <'a, const N: usize> From<&'a FrameBuf<N>> for Frame<'a> {
+    fn from(frame: &'a FrameBuf<N>) -> Self {
+        frame.as_frame()
+    }
 }
 
 enum FrameIteratorState {
@@ -49,19 +215,27 @@ enum FrameIteratorState {
 /// checksum.
 pub struct FrameIterator<'a> {
     frame: &'a Frame<'a>,
+    mode: ApiMode,
     state: FrameIteratorState,
     state_index: usize,
-    checksum: Wrapping<u8>
+    checksum: Wrapping<u8>,
+
+    // The second half of an escape sequence that still has to be sent
+    escaped: Option<u8>
 }
 
 impl <'a> Iterator for FrameIterator<'a> {
     type Item = u8;
 
     fn next(&mut self) -> Option<u8> {
+        if let Some(escaped) = self.escaped.take() {
+            return Some(escaped);
+        }
+
         let return_val: u8 = match self.state {
             FrameIteratorState::Delimiter => {
                 self.state = FrameIteratorState::LengthMsb;
-                0x7E
+                return Some(START_DELIMITER);
             },
             FrameIteratorState::LengthMsb => {
                 self.state = FrameIteratorState::LengthLsb;
@@ -80,7 +254,7 @@ impl <'a> Iterator for FrameIterator<'a> {
                     self.state = FrameIteratorState::Checksum;
                 }
 
-                self.checksum = self.checksum + Wrapping(current_byte);
+                self.checksum += Wrapping(current_byte);
 
                 current_byte
             },
@@ -95,6 +269,11 @@ impl <'a> Iterator for FrameIterator<'a> {
             }
         };
 
+        if self.mode == ApiMode::Escaped && needs_escape(return_val) {
+            self.escaped = Some(escape(return_val));
+            return Some(ESCAPE);
+        }
+
         Some(return_val)
     }
 }
@@ -108,10 +287,8 @@ fn test_serialize() {
 
     let expected: [u8; 9] = [0x7E, 0x00, 0x05, 0x08, 0x01, 0x4E, 0x4A, 0xFF, 0x5F];
 
-    let mut index = 0;
-    for ch in frame.serialize() {
+    for (index, ch) in frame.serialize().enumerate() {
         assert_eq!(ch, expected[index]);
-        index += 1;
     }
 }
 
@@ -125,15 +302,13 @@ fn test_from_command_success() {
 
     match frame {
         Ok(frame) => {
-            let mut index = 0;
             let expected: [u8; 8] = [0x7E, 0x00, 0x04, 0x08, frame_id, 0x4E, 0x4A, 0x0D];
-            for ch in frame.serialize() {
+            for (index, ch) in frame.serialize().enumerate() {
                 assert_eq!(ch, expected[index]);
-                index += 1;
             }
         },
         _ => {
-            assert!(false);
+            panic!("the frame should have fit in the buffer");
         }
     }
 }
@@ -145,5 +320,75 @@ fn test_from_command_failure() {
     let command = super::commands::AtCommand::new(0x52, [b'N', b'J'], None);
     let f = Frame::from_command(command, &mut buffer[..]);
 
-    assert_eq!(f.is_err(), true);
+    assert!(f.is_err());
+}
+
+#[test]
+fn test_serialize_escaped() {
+    // A transmit request with bytes that need escaping in the address and data
+    let data: [u8; 15] = [
+        0x10, 0x01, 0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27, 0xFF, 0xFE, 0x00, 0x00, 0x7D
+    ];
+    let frame = Frame::new(&data);
+
+    // The checksum is computed over the unescaped bytes
+    let expected: [u8; 21] = [
+        0x7E, 0x00, 0x0F, 0x10, 0x01, 0x00, 0x7D, 0x33, 0xA2, 0x00, 0x40, 0x0A,
+        0x01, 0x27, 0xFF, 0xFE, 0x00, 0x00, 0x7D, 0x5D, 0x4D
+    ];
+
+    let mut count = 0;
+    for (index, ch) in frame.serialize_with_mode(ApiMode::Escaped).enumerate() {
+        assert_eq!(ch, expected[index]);
+        count += 1;
+    }
+
+    assert_eq!(count, expected.len());
+}
+
+#[test]
+fn test_encode_command() {
+    use super::commands::{ SliceWriter, TransmitRequestCommand };
+
+    // The same transmit request as in test_serialize_escaped
+    let command = TransmitRequestCommand::to_destination(0x01, 0x0013_A200_400A_0127, &[0x7D]);
+    assert_eq!(command.encoded_len(), 15);
+
+    let mut buffer: [u8; 21] = unsafe { core::mem::zeroed() };
+    let mut writer = SliceWriter { buffer: &mut buffer, position: 0 };
+    Frame::encode_command(&command, ApiMode::Escaped, &mut writer).unwrap();
+    assert_eq!(writer.position, 21);
+
+    let mut data: [u8; 15] = unsafe { core::mem::zeroed() };
+    let frame = Frame::from_command(command, &mut data).unwrap();
+    for (index, ch) in frame.serialize_with_mode(ApiMode::Escaped).enumerate() {
+        assert_eq!(ch, buffer[index]);
+    }
+}
+
+#[test]
+fn test_encode_command_failure() {
+    use super::commands::SliceWriter;
+
+    let command = super::commands::AtCommand::new(0x52, [b'N', b'J'], None);
+    let mut buffer: [u8; 7] = unsafe { core::mem::zeroed() };
+    let mut writer = SliceWriter { buffer: &mut buffer, position: 0 };
+
+    assert_eq!(Frame::encode_command(&command, ApiMode::Unescaped, &mut writer), Err(BufferSizeError));
+}
+
+#[test]
+fn test_frame_buf() {
+    let command = super::commands::AtCommand::new(0x01, [b'N', b'J'], Some(b"\xFF"));
+    let owned: FrameBuf<8> = FrameBuf::from_command(command).unwrap();
+    assert_eq!(owned.data(), [0x08, 0x01, 0x4E, 0x4A, 0xFF]);
+
+    // Round trip through a borrowed frame
+    let frame = Frame::from(&owned);
+    assert_eq!(FrameBuf::<8>::try_from(frame), Ok(owned.clone()));
+    assert_eq!(FrameBuf::<5>::try_from(frame).unwrap().data(), owned.data());
+    assert_eq!(FrameBuf::<4>::try_from(frame), Err(BufferSizeError));
+
+    let expected: [u8; 9] = [0x7E, 0x00, 0x05, 0x08, 0x01, 0x4E, 0x4A, 0xFF, 0x5F];
+    assert!(owned.as_frame().serialize().eq(expected.iter().copied()));
 }
@@ -9,6 +9,15 @@ pub mod frame;
 pub mod commands;
 pub mod responses;
 pub mod buffer;
+pub mod routing;
+pub mod security;
+pub mod receiver;
+#[cfg(feature = "embedded-hal")]
+pub mod xbee;
 
-pub use frame::{Frame, FrameIterator};
-pub use buffer::{FrameBuffer};
\ No newline at end of file
+pub use frame::{Frame, FrameBuf, FrameIterator, ApiMode};
+pub use buffer::{FrameBuffer, FrameError};
+pub use routing::SourceRouteTable;
+pub use receiver::SplitReceiver;
+#[cfg(feature = "embedded-hal")]
+pub use xbee::{XBee, XBeeError};
\ No newline at end of file
@@ -0,0 +1,234 @@
+use core::cell::UnsafeCell;
+use core::ops::Deref;
+use core::sync::atomic::{ AtomicBool, Ordering };
+use super::buffer::{ FrameBuffer, FrameError };
+use super::frame::{ ApiMode, FrameBuf };
+
+/// A place for one completed frame. `full` hands the frame back and forth
+/// between the producer, which may only write it while it is clear, and
+/// the consumer, which may only read it while it is set.
+struct Slot<const N: usize> {
+  full: AtomicBool,
+  frame: UnsafeCell<FrameBuf<N>>
+}
+
+// Access to `frame` is serialized by `full`, see above
+unsafe impl <const N: usize> Sync for Slot<N> {}
+
+impl <const N: usize> Slot<N> {
+  // Only used to initialize the array of slots
+  #[allow(clippy::declare_interior_mutable_const)]
+  const EMPTY: Slot<N> = Slot { full: AtomicBool::new(false), frame: UnsafeCell::new(FrameBuf::new()) };
+}
+
+/// Receives frames of up to `N` bytes into a ring of `K` slots, so that
+/// bytes can keep arriving while earlier frames are still being handled.
+/// It is split into a `Producer`, usually fed from a UART interrupt, and a
+/// `Consumer` that takes completed frames in the main loop. Only atomic
+/// loads and stores are used, so it also works on cores without
+/// compare-and-swap instructions.
+pub struct SplitReceiver<const N: usize, const K: usize> {
+  scratch: [u8; N],
+  slots: [Slot<N>; K]
+}
+
+impl <const N: usize, const K: usize> SplitReceiver<N, K> {
+  /// An empty receiver. `new` is a `const fn`, so the receiver can live in
+  /// a `static` that is split once at startup, with the `Producer` handed
+  /// to the UART interrupt.
+  pub const fn new() -> Self {
+    SplitReceiver { scratch: [0; N], slots: [Slot::EMPTY; K] }
+  }
+
+  /// Splits the receiver for frames sent in unescaped API mode.
+  pub fn split(&mut self) -> (Producer<'_, N, K>, Consumer<'_, N, K>) {
+    self.split_with_mode(ApiMode::Unescaped)
+  }
+
+  /// Splits the receiver for frames sent in the given API `mode`.
+  pub fn split_with_mode(&mut self, mode: ApiMode) -> (Producer<'_, N, K>, Consumer<'_, N, K>) {
+    let slots = &self.slots;
+
+    // Slots left over from an earlier split are handed out again
+    for slot in slots.iter() {
+      slot.full.store(false, Ordering::Relaxed);
+    }
+
+    let producer = Producer {
+      frame_buffer: FrameBuffer::with_mode(&mut self.scratch, mode),
+      slots,
+      write: 0,
+      dropped: 0
+    };
+
+    (producer, Consumer { slots, read: 0 })
+  }
+}
+
+impl <const N: usize, const K: usize> Default for SplitReceiver<N, K> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// The half of a `SplitReceiver` that bytes are pushed into.
+pub struct Producer<'a, const N: usize, const K: usize> {
+  frame_buffer: FrameBuffer<'a>,
+  slots: &'a [Slot<N>; K],
+  write: usize,
+  dropped: u32
+}
+
+impl <'a, const N: usize, const K: usize> Producer<'a, N, K> {
+  /// Receives a single byte. A completed frame is moved into the next
+  /// slot, or dropped if the consumer still holds every slot. Malformed
+  /// frames are reported like `FrameBuffer::receive` does.
+  pub fn push(&mut self, received: u8) -> Result<(), FrameError> {
+    let frame = match self.frame_buffer.receive(received)? {
+      Some(frame) => frame,
+      None => return Ok(())
+    };
+
+    let slot = match self.slots.get(self.write) {
+      Some(slot) if !slot.full.load(Ordering::Acquire) => slot,
+      _ => {
+        self.dropped = self.dropped.wrapping_add(1);
+        return Ok(());
+      }
+    };
+
+    // The slot is clear, so the consumer is not reading it. The scratch
+    // buffer is N bytes, so the frame always fits.
+    unsafe { (*slot.frame.get()).copy_from(frame.data).ok(); }
+    slot.full.store(true, Ordering::Release);
+    self.write = (self.write + 1) % K;
+
+    Ok(())
+  }
+
+  /// The number of completed frames dropped because no slot was free.
+  pub fn dropped_frames(&self) -> u32 {
+    self.dropped
+  }
+
+  /// See `FrameBuffer::discarded_bytes`.
+  pub fn discarded_bytes(&self) -> u32 {
+    self.frame_buffer.discarded_bytes()
+  }
+}
+
+/// The half of a `SplitReceiver` that completed frames are taken from.
+pub struct Consumer<'a, const N: usize, const K: usize> {
+  slots: &'a [Slot<N>; K],
+  read: usize
+}
+
+impl <'a, const N: usize, const K: usize> Consumer<'a, N, K> {
+  /// Takes the oldest completed frame, if there is one. Its slot is not
+  /// reused until the returned guard is dropped.
+  pub fn next_frame(&mut self) -> Option<FrameGuard<'_, 'a, N, K>> {
+    match self.slots.get(self.read) {
+      Some(slot) if slot.full.load(Ordering::Acquire) => {},
+      _ => return None
+    }
+
+    Some(FrameGuard { consumer: self })
+  }
+}
+
+/// A completed frame borrowed from a `Consumer`, which dereferences to a
+/// `FrameBuf`. Dropping it hands the slot back to the producer.
+pub struct FrameGuard<'c, 'a, const N: usize, const K: usize> {
+  consumer: &'c mut Consumer<'a, N, K>
+}
+
+impl <'c, 'a, const N: usize, const K: usize> Deref for FrameGuard<'c, 'a, N, K> {
+  type Target = FrameBuf<N>;
+
+  fn deref(&self) -> &FrameBuf<N> {
+    // The slot is full, so the producer does not write to it
+    unsafe { &*self.consumer.slots[self.consumer.read].frame.get() }
+  }
+}
+
+impl <'c, 'a, const N: usize, const K: usize> Drop for FrameGuard<'c, 'a, N, K> {
+  fn drop(&mut self) {
+    let consumer = &mut *self.consumer;
+
+    consumer.slots[consumer.read].full.store(false, Ordering::Release);
+    consumer.read = (consumer.read + 1) % K;
+  }
+}
+
+#[cfg(test)]
+const EXAMPLE_FRAME: [u8; 9] = [0x7E, 0x00, 0x05, 0x08, 0x01, 0x4E, 0x4A, 0xFF, 0x5F];
+
+#[test]
+fn test_split_receiver() {
+  let mut receiver: SplitReceiver<16, 2> = SplitReceiver::new();
+  let (mut producer, mut consumer) = receiver.split();
+
+  assert!(consumer.next_frame().is_none());
+
+  // Fill both slots, the third frame has nowhere to go
+  for frame_id in 1..4 {
+    let mut frame = EXAMPLE_FRAME;
+    frame[4] = frame_id;
+    frame[8] -= frame_id - 1;
+
+    for character in frame.iter() {
+      producer.push(*character).unwrap();
+    }
+  }
+  assert_eq!(producer.dropped_frames(), 1);
+
+  // A held frame is not overwritten by the next one
+  let frame = consumer.next_frame().unwrap();
+  assert_eq!(frame.data(), [0x08, 0x01, 0x4E, 0x4A, 0xFF]);
+  for character in EXAMPLE_FRAME.iter() {
+    producer.push(*character).unwrap();
+  }
+  assert_eq!(producer.dropped_frames(), 2);
+  assert_eq!(frame.data(), [0x08, 0x01, 0x4E, 0x4A, 0xFF]);
+  drop(frame);
+
+  assert_eq!(consumer.next_frame().unwrap().data(), [0x08, 0x02, 0x4E, 0x4A, 0xFF]);
+  assert!(consumer.next_frame().is_none());
+}
+
+#[test]
+fn test_split_receiver_threads() {
+  let mut receiver: SplitReceiver<16, 4> = SplitReceiver::new();
+  let (mut producer, mut consumer) = receiver.split();
+  let done = AtomicBool::new(false);
+
+  std::thread::scope(|scope| {
+    let sender = scope.spawn(|| {
+      for _ in 0..1000 {
+        for character in EXAMPLE_FRAME.iter() {
+          producer.push(*character).unwrap();
+        }
+      }
+
+      done.store(true, Ordering::Release);
+      producer.dropped_frames()
+    });
+
+    // Every frame that was not dropped arrives intact
+    let mut received = 0;
+    loop {
+      let finished = done.load(Ordering::Acquire);
+
+      match consumer.next_frame() {
+        Some(frame) => {
+          assert_eq!(frame.data(), [0x08, 0x01, 0x4E, 0x4A, 0xFF]);
+          received += 1;
+        },
+        None if finished => break,
+        None => {}
+      }
+    }
+
+    assert_eq!(received + sender.join().unwrap(), 1000);
+  });
+}
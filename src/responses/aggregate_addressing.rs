@@ -0,0 +1,44 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0x8E;
+
+/// Sent by a DigiMesh node when an aggregate addressing broadcast changes
+/// its `DH`/`DL` destination address.
+pub struct AggregateAddressingUpdate {
+  pub format_id: u8,
+  pub new_address: u64,
+  pub old_address: u64
+}
+
+impl <'a> Response<'a, AggregateAddressingUpdate> for AggregateAddressingUpdate {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 18 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    Ok(Self {
+      format_id: buffer[1],
+      new_address: buffer_to_u64(&buffer[2..10]),
+      old_address: buffer_to_u64(&buffer[10..18])
+    })
+  }
+}
+
+#[test]
+fn test_aggregate_addressing_update_parse() {
+  let buffer: [u8; 18] = [
+    0x8E,
+    0x00,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF
+  ];
+  let response = AggregateAddressingUpdate::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.format_id, 0x00);
+  assert_eq!(response.new_address, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.old_address, 0x0000_0000_0000_FFFF);
+  assert_eq!(AggregateAddressingUpdate::parse(&buffer[..17]).err(), Some(ResponseError::SizeIncorrectError));
+}
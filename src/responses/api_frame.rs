@@ -0,0 +1,174 @@
+use super::{
+  Response, ResponseError,
+  AtCommandResponse, ModemStatus, ZigbeeReceivePacket, ZigbeeTransmitStatus,
+  RemoteAtCommandResponse, ZigbeeExplicitRxIndicator, IoDataSampleRxIndicator,
+  NodeIdentification, XBeeSensorRead, RouteRecordIndicator, ManyToOneRouteRequestIndicator,
+  RegisterJoiningDeviceStatus
+};
+#[cfg(feature = "ieee802154")]
+use super::{ RxPacket64, RxPacket16, TxStatus };
+#[cfg(feature = "digimesh")]
+use super::{ RouteInformation, AggregateAddressingUpdate };
+use crate::frame::Frame;
+use core::convert::TryFrom;
+
+/// A received frame parsed into whichever response type its frame type
+/// byte identifies. Frame types this crate does not know about are kept
+/// as `Unknown` so that they can still be handled by the application.
+pub enum ApiFrame<'a> {
+  AtCommandResponse(AtCommandResponse<'a>),
+  ModemStatus(ModemStatus),
+  ZigbeeReceivePacket(ZigbeeReceivePacket<'a>),
+  ZigbeeTransmitStatus(ZigbeeTransmitStatus),
+  RemoteAtCommandResponse(RemoteAtCommandResponse<'a>),
+  ZigbeeExplicitRxIndicator(ZigbeeExplicitRxIndicator<'a>),
+  IoDataSampleRxIndicator(IoDataSampleRxIndicator<'a>),
+  NodeIdentification(NodeIdentification<'a>),
+  XBeeSensorRead(XBeeSensorRead),
+  RouteRecordIndicator(RouteRecordIndicator<'a>),
+  ManyToOneRouteRequestIndicator(ManyToOneRouteRequestIndicator),
+  RegisterJoiningDeviceStatus(RegisterJoiningDeviceStatus),
+  #[cfg(feature = "ieee802154")]
+  RxPacket64(RxPacket64<'a>),
+  #[cfg(feature = "ieee802154")]
+  RxPacket16(RxPacket16<'a>),
+  #[cfg(feature = "ieee802154")]
+  TxStatus(TxStatus),
+  #[cfg(feature = "digimesh")]
+  RouteInformation(RouteInformation),
+  #[cfg(feature = "digimesh")]
+  AggregateAddressingUpdate(AggregateAddressingUpdate),
+  Unknown { frame_type: u8, payload: &'a [u8] }
+}
+
+impl <'a> ApiFrame<'a> {
+  /// Parses the data of a frame, starting with the frame type byte.
+  pub fn parse(buffer: &'a [u8]) -> Result<Self, ResponseError> {
+    let frame_type = match buffer.first() {
+      Some(frame_type) => *frame_type,
+      None => return Err(ResponseError::SizeIncorrectError)
+    };
+
+    match frame_type {
+      id if AtCommandResponse::respond_to(id) =>
+        AtCommandResponse::parse(buffer).map(ApiFrame::AtCommandResponse),
+      id if ModemStatus::respond_to(id) =>
+        ModemStatus::parse(buffer).map(ApiFrame::ModemStatus),
+      id if ZigbeeReceivePacket::respond_to(id) =>
+        ZigbeeReceivePacket::parse(buffer).map(ApiFrame::ZigbeeReceivePacket),
+      id if ZigbeeTransmitStatus::respond_to(id) =>
+        ZigbeeTransmitStatus::parse(buffer).map(ApiFrame::ZigbeeTransmitStatus),
+      id if RemoteAtCommandResponse::respond_to(id) =>
+        RemoteAtCommandResponse::parse(buffer).map(ApiFrame::RemoteAtCommandResponse),
+      id if ZigbeeExplicitRxIndicator::respond_to(id) =>
+        ZigbeeExplicitRxIndicator::parse(buffer).map(ApiFrame::ZigbeeExplicitRxIndicator),
+      id if IoDataSampleRxIndicator::respond_to(id) =>
+        IoDataSampleRxIndicator::parse(buffer).map(ApiFrame::IoDataSampleRxIndicator),
+      id if NodeIdentification::respond_to(id) =>
+        NodeIdentification::parse(buffer).map(ApiFrame::NodeIdentification),
+      id if XBeeSensorRead::respond_to(id) =>
+        XBeeSensorRead::parse(buffer).map(ApiFrame::XBeeSensorRead),
+      id if RouteRecordIndicator::respond_to(id) =>
+        RouteRecordIndicator::parse(buffer).map(ApiFrame::RouteRecordIndicator),
+      id if ManyToOneRouteRequestIndicator::respond_to(id) =>
+        ManyToOneRouteRequestIndicator::parse(buffer).map(ApiFrame::ManyToOneRouteRequestIndicator),
+      id if RegisterJoiningDeviceStatus::respond_to(id) =>
+        RegisterJoiningDeviceStatus::parse(buffer).map(ApiFrame::RegisterJoiningDeviceStatus),
+      #[cfg(feature = "ieee802154")]
+      id if RxPacket64::respond_to(id) => RxPacket64::parse(buffer).map(ApiFrame::RxPacket64),
+      #[cfg(feature = "ieee802154")]
+      id if RxPacket16::respond_to(id) => RxPacket16::parse(buffer).map(ApiFrame::RxPacket16),
+      #[cfg(feature = "ieee802154")]
+      id if TxStatus::respond_to(id) => TxStatus::parse(buffer).map(ApiFrame::TxStatus),
+      #[cfg(feature = "digimesh")]
+      id if RouteInformation::respond_to(id) => RouteInformation::parse(buffer).map(ApiFrame::RouteInformation),
+      #[cfg(feature = "digimesh")]
+      id if AggregateAddressingUpdate::respond_to(id) =>
+        AggregateAddressingUpdate::parse(buffer).map(ApiFrame::AggregateAddressingUpdate),
+      _ => Ok(ApiFrame::Unknown { frame_type, payload: &buffer[1..] })
+    }
+  }
+}
+
+impl <'a> TryFrom<Frame<'a>> for ApiFrame<'a> {
+  type Error = ResponseError;
+
+  fn try_from(frame: Frame<'a>) -> Result<Self, ResponseError> {
+    ApiFrame::parse(frame.data)
+  }
+}
+
+#[test]
+fn test_api_frame_at_command_response() {
+  let buffer: [u8; 6] = [0x88, 0x01, 0x42, 0x44, 0x00, 0x07];
+  let frame = Frame::new(&buffer);
+
+  match ApiFrame::try_from(frame) {
+    Ok(ApiFrame::AtCommandResponse(response)) => {
+      assert_eq!(response.at_command, [b'B', b'D']);
+      assert_eq!(response.command_data, Some(&[0x07][..]));
+    },
+    _ => panic!("expected an AT command response")
+  }
+}
+
+#[test]
+fn test_api_frame_receive_packet() {
+  let buffer: [u8; 14] = [
+    0x90,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    0x01,
+    0x52, 0x78
+  ];
+
+  match ApiFrame::parse(&buffer) {
+    Ok(ApiFrame::ZigbeeReceivePacket(packet)) => assert_eq!(packet.data, [0x52, 0x78]),
+    _ => panic!("expected a receive packet")
+  }
+}
+
+#[test]
+fn test_api_frame_many_to_one_route_request() {
+  let buffer: [u8; 12] = [
+    0xA3,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x40, 0x11, 0x22,
+    0x00, 0x00,
+    0x00
+  ];
+
+  match ApiFrame::parse(&buffer) {
+    Ok(ApiFrame::ManyToOneRouteRequestIndicator(request)) => assert_eq!(request.source, 0x0013_A200_4040_1122),
+    _ => panic!("expected a many-to-one route request")
+  }
+}
+
+#[cfg(feature = "ieee802154")]
+#[test]
+fn test_api_frame_rx_packet_16() {
+  let buffer: [u8; 6] = [0x81, 0x12, 0x34, 0x28, 0x00, 0x52];
+
+  match ApiFrame::parse(&buffer) {
+    Ok(ApiFrame::RxPacket16(packet)) => assert_eq!(packet.source, 0x1234),
+    _ => panic!("expected an 802.15.4 receive packet")
+  }
+}
+
+#[test]
+fn test_api_frame_unknown() {
+  let buffer: [u8; 3] = [0xFE, 0x01, 0x02];
+
+  match ApiFrame::parse(&buffer) {
+    Ok(ApiFrame::Unknown { frame_type, payload }) => {
+      assert_eq!(frame_type, 0xFE);
+      assert_eq!(payload, [0x01, 0x02]);
+    },
+    _ => panic!("expected an unknown frame")
+  }
+}
+
+#[test]
+fn test_api_frame_errors() {
+  assert_eq!(ApiFrame::parse(&[]).err(), Some(ResponseError::SizeIncorrectError));
+  assert_eq!(ApiFrame::parse(&[0x88, 0x01]).err(), Some(ResponseError::SizeIncorrectError));
+}
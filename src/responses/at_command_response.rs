@@ -3,6 +3,7 @@ use core::convert::TryFrom;
 
 const COMMAND_ID: u8 = 0x88;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AtCommandResponseStatus {
   Ok = 0,
   Error = 1,
@@ -26,34 +27,30 @@ impl TryFrom<u8> for AtCommandResponseStatus {
   }
 }
 
-#[allow(dead_code)]
-pub struct AtCommandResponse {
-  frame_id: u8,
-  at_command: [u8; 2],
-  command_status: AtCommandResponseStatus,
-  command_data: Option<u8>,
+pub struct AtCommandResponse<'a> {
+  pub frame_id: u8,
+  pub at_command: [u8; 2],
+  pub command_status: AtCommandResponseStatus,
+  pub command_data: Option<&'a [u8]>,
 }
 
-impl <'a> Response<'a, AtCommandResponse> for AtCommandResponse {
+impl <'a> Response<'a, AtCommandResponse<'a>> for AtCommandResponse<'a> {
   fn respond_to(id: u8) -> bool {
     COMMAND_ID == id
   }
 
-  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
-    if buffer.len() < 5 || buffer.len() > 6 { return Err(ResponseError::SizeIncorrectError); }
+  fn parse(buffer: &'a [u8]) -> Result<Self, ResponseError> {
+    if buffer.len() < 5 { return Err(ResponseError::SizeIncorrectError); }
     if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
 
     let frame_id = buffer[1];
     let at_command = [buffer[2], buffer[3]];
 
-    let command_status = match AtCommandResponseStatus::try_from(buffer[4]) {
-      Ok(status) => status,
-      Err(error) => return Err(error)
-    };
+    let command_status = AtCommandResponseStatus::try_from(buffer[4])?;
 
     let command_data = match buffer.len() {
-      6 => Some(buffer[5]),
-      _ => None
+      5 => None,
+      _ => Some(&buffer[5..])
     };
 
     Ok(Self { frame_id, at_command, command_status, command_data })
@@ -71,13 +68,25 @@ fn test_at_command_response_parse() {
   assert_eq!(response.command_data, None);
 }
 
+#[test]
+fn test_at_command_response_multi_byte() {
+  let buffer: [u8; 13] = [0x88, 0x01, 0x53, 0x48, 0x00, 0x00, 0x13, 0xA2, 0x00, 0x00, 0x00, 0x00, 0x00];
+  let response = AtCommandResponse::parse(&buffer[..9]).unwrap();
+
+  assert_eq!(response.at_command, [b'S', b'H']);
+  assert_eq!(response.command_data, Some(&[0x00, 0x13, 0xA2, 0x00][..]));
+
+  let response = AtCommandResponse::parse(&buffer[..]).unwrap();
+  assert_eq!(response.command_data.unwrap().len(), 8);
+}
+
 #[test]
 fn test_bad_enum() {
   let buffer: [u8; 5] = [0x88, 0x01, 0x42, 0x44, 0x10];
   let response = AtCommandResponse::parse(&buffer[..]);
 
   match response {
-    Ok(_) => assert!(false),
+    Ok(_) => panic!("the response should not have parsed"),
     Err(err) => assert_eq!(err, ResponseError::EnumComparisonError)
   };
 }
@@ -88,7 +97,7 @@ fn test_bad_size() {
   let response = AtCommandResponse::parse(&buffer[..]);
 
   match response {
-    Ok(_) => assert!(false),
+    Ok(_) => panic!("the response should not have parsed"),
     Err(err) => assert_eq!(err, ResponseError::SizeIncorrectError)
   };
 }
@@ -0,0 +1,100 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0x91;
+
+/// A received packet together with its application layer addressing.
+/// The XBee sends these instead of a `ZigbeeReceivePacket` when `AO` is 1.
+pub struct ZigbeeExplicitRxIndicator<'a> {
+  pub source: u64,
+  pub network_address: u16,
+  pub source_endpoint: u8,
+  pub destination_endpoint: u8,
+  pub cluster_id: u16,
+  pub profile_id: u16,
+  pub packet_acknowledged: bool,
+  pub packet_broadcast: bool,
+  pub packet_encrypted: bool,
+  pub packet_end_device: bool,
+  pub data: &'a [u8]
+}
+
+impl <'a> Response<'a, ZigbeeExplicitRxIndicator<'a>> for ZigbeeExplicitRxIndicator<'a> {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<ZigbeeExplicitRxIndicator<'a>, ResponseError> {
+    if buffer.len() < 18 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let source = buffer_to_u64(&buffer[1..9]);
+    let network_address: u16 = buffer[9] as u16 * 256 + buffer[10] as u16;
+    let source_endpoint = buffer[11];
+    let destination_endpoint = buffer[12];
+    let cluster_id: u16 = buffer[13] as u16 * 256 + buffer[14] as u16;
+    let profile_id: u16 = buffer[15] as u16 * 256 + buffer[16] as u16;
+    let packet_acknowledged = buffer[17] & 0x01 != 0;
+    let packet_broadcast = buffer[17] & 0x02 != 0;
+    let packet_encrypted = buffer[17] & 0x20 != 0;
+    let packet_end_device = buffer[17] & 0x40 != 0;
+    let data = &buffer[18..];
+
+    Ok(Self {
+      source,
+      network_address,
+      source_endpoint,
+      destination_endpoint,
+      cluster_id,
+      profile_id,
+      packet_acknowledged,
+      packet_broadcast,
+      packet_encrypted,
+      packet_end_device,
+      data
+    })
+  }
+}
+
+#[test]
+fn test_explicit_rx_indicator_parse() {
+  let buffer: [u8; 21] = [
+    0x91,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    0x01,
+    0xE8,
+    0x00, 0x06,
+    0x01, 0x04,
+    0x22,
+    0x18, 0x01, 0x0A
+  ];
+  let response = ZigbeeExplicitRxIndicator::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.network_address, 0x7D84);
+  assert_eq!(response.source_endpoint, 0x01);
+  assert_eq!(response.destination_endpoint, 0xE8);
+  assert_eq!(response.cluster_id, 0x0006);
+  assert_eq!(response.profile_id, 0x0104);
+  assert!(!response.packet_acknowledged);
+  assert!(response.packet_broadcast);
+  assert!(response.packet_encrypted);
+  assert!(!response.packet_end_device);
+  assert_eq!(response.data, [0x18, 0x01, 0x0A]);
+}
+
+#[test]
+fn test_explicit_rx_indicator_bad_size() {
+  let buffer: [u8; 17] = [
+    0x91,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    0x01,
+    0xE8,
+    0x00, 0x06,
+    0x01, 0x04
+  ];
+  let response = ZigbeeExplicitRxIndicator::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
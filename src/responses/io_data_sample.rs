@@ -0,0 +1,256 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0x92;
+const SUPPLY_VOLTAGE_BIT: u8 = 7;
+
+/// An input pin that can be included in an IO sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pin {
+  /// Digital line DIOn.
+  Dio(u8),
+  /// Analog input ADn.
+  Adc(u8),
+  /// The supply voltage monitor.
+  SupplyVoltage
+}
+
+/// The value read from a single pin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+  /// The level of a digital line.
+  Digital(bool),
+  /// A 10-bit ADC reading.
+  Analog(u16)
+}
+
+impl Sample {
+  /// Converts an analog reading to millivolts, given the reference voltage
+  /// of the ADC in millivolts. Returns None for digital samples.
+  pub fn millivolts(&self, reference_millivolts: u32) -> Option<u32> {
+    match *self {
+      Sample::Analog(counts) => Some(counts as u32 * reference_millivolts / 1023),
+      Sample::Digital(_) => None
+    }
+  }
+}
+
+/// A single set of IO samples, as carried by an IO Data Sample RX Indicator
+/// or returned as the data of an `IS` AT command response. The masks are
+/// read-only, since the analog samples are located through them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IoSample<'a> {
+  digital_mask: u16,
+  analog_mask: u8,
+  pub digital_samples: u16,
+  analog_samples: &'a [u8]
+}
+
+impl <'a> IoSample<'a> {
+  /// Parses sample data starting at the number of samples byte.
+  pub fn parse(buffer: &'a [u8]) -> Result<Self, ResponseError> {
+    if buffer.len() < 4 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] == 0 { return Err(ResponseError::SizeIncorrectError); }
+
+    let digital_mask: u16 = buffer[1] as u16 * 256 + buffer[2] as u16;
+    let analog_mask = buffer[3];
+
+    // Digital samples are only present if any digital line is enabled
+    let (digital_samples, analog_start) = if digital_mask != 0 {
+      if buffer.len() < 6 { return Err(ResponseError::SizeIncorrectError); }
+      (buffer[4] as u16 * 256 + buffer[5] as u16, 6)
+    } else {
+      (0, 4)
+    };
+
+    let analog_length = 2 * analog_mask.count_ones() as usize;
+    if buffer.len() != analog_start + analog_length { return Err(ResponseError::SizeIncorrectError); }
+
+    Ok(Self {
+      digital_mask,
+      analog_mask,
+      digital_samples,
+      analog_samples: &buffer[analog_start..]
+    })
+  }
+
+  /// The digital lines that were sampled, DIO0 in the lowest bit.
+  pub fn digital_mask(&self) -> u16 {
+    self.digital_mask
+  }
+
+  /// The analog inputs that were sampled, AD0 in the lowest bit and the
+  /// supply voltage in bit 7.
+  pub fn analog_mask(&self) -> u8 {
+    self.analog_mask
+  }
+
+  /// The state of digital line DIOn, if it was sampled.
+  pub fn digital(&self, line: u8) -> Option<bool> {
+    if line >= 16 || self.digital_mask & (1 << line) == 0 { return None; }
+
+    Some(self.digital_samples & (1 << line) != 0)
+  }
+
+  /// The reading of analog input ADn, if it was sampled. Channel 7 is the
+  /// supply voltage.
+  pub fn analog(&self, channel: u8) -> Option<u16> {
+    if channel >= 8 || self.analog_mask & (1 << channel) == 0 { return None; }
+
+    // Samples are ordered by channel, so count the enabled channels below
+    let index = (self.analog_mask & ((1 << channel) - 1)).count_ones() as usize;
+    Some(self.analog_samples[2 * index] as u16 * 256 + self.analog_samples[2 * index + 1] as u16)
+  }
+
+  /// Iterates through every sampled pin, digital lines first.
+  pub fn iter(&self) -> IoSampleIterator<'a> {
+    IoSampleIterator { sample: *self, index: 0, analog_index: 0 }
+  }
+}
+
+impl <'a> IntoIterator for IoSample<'a> {
+  type Item = (Pin, Sample);
+  type IntoIter = IoSampleIterator<'a>;
+
+  fn into_iter(self) -> IoSampleIterator<'a> {
+    self.iter()
+  }
+}
+
+/// Yields a `(Pin, Sample)` for every pin enabled in an `IoSample`.
+pub struct IoSampleIterator<'a> {
+  sample: IoSample<'a>,
+  index: u8,
+  analog_index: usize
+}
+
+impl <'a> Iterator for IoSampleIterator<'a> {
+  type Item = (Pin, Sample);
+
+  fn next(&mut self) -> Option<(Pin, Sample)> {
+    // The first sixteen positions are digital lines, the next eight analog
+    while self.index < 24 {
+      let index = self.index;
+      self.index += 1;
+
+      if index < 16 {
+        if let Some(level) = self.sample.digital(index) {
+          return Some((Pin::Dio(index), Sample::Digital(level)));
+        }
+      } else {
+        let channel = index - 16;
+        if self.sample.analog_mask & (1 << channel) == 0 { continue; }
+
+        let offset = 2 * self.analog_index;
+        self.analog_index += 1;
+
+        let samples = self.sample.analog_samples;
+        let counts = samples[offset] as u16 * 256 + samples[offset + 1] as u16;
+        let pin = if channel == SUPPLY_VOLTAGE_BIT { Pin::SupplyVoltage } else { Pin::Adc(channel) };
+
+        return Some((pin, Sample::Analog(counts)));
+      }
+    }
+
+    None
+  }
+}
+
+/// Sent when a remote device transmits an IO sample, either periodically
+/// (`IR`) or on a change detection (`IC`).
+pub struct IoDataSampleRxIndicator<'a> {
+  pub source: u64,
+  pub network_address: u16,
+  pub packet_acknowledged: bool,
+  pub packet_broadcast: bool,
+  pub sample: IoSample<'a>
+}
+
+impl <'a> Response<'a, IoDataSampleRxIndicator<'a>> for IoDataSampleRxIndicator<'a> {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<IoDataSampleRxIndicator<'a>, ResponseError> {
+    if buffer.len() < 16 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let source = buffer_to_u64(&buffer[1..9]);
+    let network_address: u16 = buffer[9] as u16 * 256 + buffer[10] as u16;
+    let packet_acknowledged = buffer[11] & 0x01 != 0;
+    let packet_broadcast = buffer[11] & 0x02 != 0;
+    let sample = IoSample::parse(&buffer[12..])?;
+
+    Ok(Self { source, network_address, packet_acknowledged, packet_broadcast, sample })
+  }
+}
+
+#[test]
+fn test_io_data_sample_parse() {
+  // The example frame from the XBee Zigbee manual
+  let buffer: [u8; 20] = [
+    0x92,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    0x01,
+    0x01,
+    0x00, 0x1C,
+    0x02,
+    0x00, 0x14,
+    0x02, 0x25
+  ];
+  let response = IoDataSampleRxIndicator::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.network_address, 0x7D84);
+  assert!(response.packet_acknowledged);
+  assert_eq!(response.sample.digital_mask(), 0x001C);
+  assert_eq!(response.sample.analog_mask(), 0x02);
+
+  assert_eq!(response.sample.digital(2), Some(true));
+  assert_eq!(response.sample.digital(3), Some(false));
+  assert_eq!(response.sample.digital(0), None);
+  assert_eq!(response.sample.analog(1), Some(0x0225));
+  assert_eq!(response.sample.analog(0), None);
+
+  let mut samples = response.sample.iter();
+  assert_eq!(samples.next(), Some((Pin::Dio(2), Sample::Digital(true))));
+  assert_eq!(samples.next(), Some((Pin::Dio(3), Sample::Digital(false))));
+  assert_eq!(samples.next(), Some((Pin::Dio(4), Sample::Digital(true))));
+  assert_eq!(samples.next(), Some((Pin::Adc(1), Sample::Analog(0x0225))));
+  assert_eq!(samples.next(), None);
+}
+
+#[test]
+fn test_io_sample_analog_only() {
+  // IS response data with AD0, AD3 and the supply voltage enabled
+  let buffer: [u8; 10] = [0x01, 0x00, 0x00, 0x89, 0x01, 0x00, 0x03, 0xFF, 0x0B, 0x6E];
+  let sample = IoSample::parse(&buffer[..]).unwrap();
+
+  assert_eq!(sample.digital_samples, 0);
+  assert_eq!(sample.analog(0), Some(0x0100));
+  assert_eq!(sample.analog(3), Some(0x03FF));
+  assert_eq!(sample.analog(7), Some(0x0B6E));
+
+  let mut samples = sample.iter();
+  assert_eq!(samples.next(), Some((Pin::Adc(0), Sample::Analog(0x0100))));
+  assert_eq!(samples.next(), Some((Pin::Adc(3), Sample::Analog(0x03FF))));
+  assert_eq!(samples.next(), Some((Pin::SupplyVoltage, Sample::Analog(0x0B6E))));
+  assert_eq!(samples.next(), None);
+}
+
+#[test]
+fn test_sample_millivolts() {
+  assert_eq!(Sample::Analog(0x03FF).millivolts(1200), Some(1200));
+  assert_eq!(Sample::Analog(0x0225).millivolts(1200), Some(643));
+  assert_eq!(Sample::Digital(true).millivolts(1200), None);
+}
+
+#[test]
+fn test_io_sample_bad_size() {
+  // The analog mask promises a sample that is missing
+  let buffer: [u8; 6] = [0x01, 0x00, 0x04, 0x02, 0x00, 0x04];
+  assert_eq!(IoSample::parse(&buffer[..]), Err(ResponseError::SizeIncorrectError));
+
+  let buffer: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
+  assert_eq!(IoSample::parse(&buffer[..]), Err(ResponseError::SizeIncorrectError));
+}
@@ -0,0 +1,52 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0xA3;
+
+/// Sent when a concentrator broadcasts a many-to-one route request. Routes
+/// recorded towards that concentrator should be considered stale.
+pub struct ManyToOneRouteRequestIndicator {
+  pub source: u64,
+  pub network_address: u16
+}
+
+impl <'a> Response<'a, ManyToOneRouteRequestIndicator> for ManyToOneRouteRequestIndicator {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 12 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let source = buffer_to_u64(&buffer[1..9]);
+    let network_address: u16 = buffer[9] as u16 * 256 + buffer[10] as u16;
+
+    Ok(Self { source, network_address })
+  }
+}
+
+#[test]
+fn test_many_to_one_route_request_parse() {
+  let buffer: [u8; 12] = [
+    0xA3,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x40, 0x11, 0x22,
+    0x00, 0x00,
+    0x00
+  ];
+  let response = ManyToOneRouteRequestIndicator::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x0013_A200_4040_1122);
+  assert_eq!(response.network_address, 0x0000);
+}
+
+#[test]
+fn test_many_to_one_route_request_bad_size() {
+  let buffer: [u8; 11] = [
+    0xA3,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x40, 0x11, 0x22,
+    0x00, 0x00
+  ];
+  let response = ManyToOneRouteRequestIndicator::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
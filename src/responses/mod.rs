@@ -1,17 +1,69 @@
 pub mod at_command_response;
 pub mod receive_packet;
+pub mod transmit_status;
+pub mod modem_status;
+pub mod remote_at_command_response;
+pub mod explicit_rx_indicator;
+pub mod io_data_sample;
+pub mod node_identification;
+pub mod sensor_read;
+pub mod route_record;
+pub mod many_to_one_route_request;
+pub mod register_joining_device_status;
+#[cfg(feature = "ieee802154")]
+pub mod rx_packet;
+#[cfg(feature = "ieee802154")]
+pub mod tx_status;
+#[cfg(feature = "digimesh")]
+pub mod route_information;
+#[cfg(feature = "digimesh")]
+pub mod aggregate_addressing;
+pub mod api_frame;
 
 pub use receive_packet::ZigbeeReceivePacket;
 pub use at_command_response::{ AtCommandResponse, AtCommandResponseStatus };
+pub use transmit_status::{ ZigbeeTransmitStatus, DeliveryStatus, DiscoveryStatus };
+pub use modem_status::{ ModemStatus, ModemStatusCode };
+pub use remote_at_command_response::RemoteAtCommandResponse;
+pub use explicit_rx_indicator::ZigbeeExplicitRxIndicator;
+pub use io_data_sample::{ IoDataSampleRxIndicator, IoSample, Pin, Sample };
+pub use node_identification::{ NodeIdentification, RemoteNode, DeviceType };
+pub use sensor_read::XBeeSensorRead;
+pub use route_record::RouteRecordIndicator;
+pub use many_to_one_route_request::ManyToOneRouteRequestIndicator;
+pub use register_joining_device_status::{ RegisterJoiningDeviceStatus, RegistrationStatus };
+#[cfg(feature = "ieee802154")]
+pub use rx_packet::{ RxPacket64, RxPacket16 };
+#[cfg(feature = "ieee802154")]
+pub use tx_status::{ TxStatus, TxDeliveryStatus };
+#[cfg(feature = "digimesh")]
+pub use route_information::{ RouteInformation, RouteInformationEvent };
+#[cfg(feature = "digimesh")]
+pub use aggregate_addressing::AggregateAddressingUpdate;
+pub use api_frame::ApiFrame;
 
 #[derive(Debug, PartialEq)]
 pub enum ResponseError {
   IdError,
   SizeIncorrectError,
   EnumComparisonError,
+  EncodingError,
 }
 
 pub trait Response<'a, T> {
   fn respond_to(id: u8) -> bool;
   fn parse(buffer: &'a [u8]) -> Result<T, ResponseError>;
 }
+
+/// Reads the first eight bytes of `buffer` as a number, MSB first.
+pub(crate) fn buffer_to_u64(buffer: &[u8]) -> u64 {
+  let mut output: u64 = 0;
+  let mut bitshift = 56;
+
+  for byte in buffer.iter().take(8) {
+    output += (*byte as u64) << bitshift;
+    bitshift -= 8;
+  }
+
+  output
+}
@@ -0,0 +1,197 @@
+use super::{ Response, ResponseError };
+
+const COMMAND_ID: u8 = 0x8A;
+
+/// The events a Modem Status frame reports. Codes that are not listed here,
+/// such as ones added by newer firmware, are kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModemStatusCode {
+  HardwareReset,
+  WatchdogTimerReset,
+  JoinedNetwork,
+  Disassociated,
+  ConfigurationError,
+  CoordinatorRealignment,
+  CoordinatorStarted,
+  NetworkSecurityKeyUpdated,
+  NetworkWokeUp,
+  NetworkWentToSleep,
+  VoltageSupplyLimitExceeded,
+  KeyEstablishmentComplete,
+  ConfigurationChangedWhileJoining,
+  AccessFault,
+  FatalStackError,
+  PlkeTableInitiated,
+  PlkeTableSuccess,
+  PlkeTableFull,
+  PlkeNotAuthorized,
+  PlkeInvalidTrustCenterRequest,
+  PlkeTrustCenterUpdateFailed,
+  PlkeBadEuiAddress,
+  PlkeLinkKeyRejected,
+  PlkeUpdateOccurred,
+  PlkeLinkKeyTableCleared,
+  FrequencyAgilityChannelChange,
+  NoJoinableBeacons,
+  TokenSpaceRecovered,
+  TokenSpaceUnrecoverable,
+  TokenSpaceCorrupted,
+  SecureSessionEstablished,
+  SecureSessionEnded,
+  SecureSessionAuthenticationFailed,
+  PanIdConflictDetected,
+  PanIdChangedByCoordinator,
+  RouterPanIdChanged,
+  NetworkWatchdogExpired,
+  JoinWindowOpened,
+  JoinWindowClosed,
+  NetworkKeyRotationInitiated,
+  StackError(u8),
+  Other(u8)
+}
+
+impl From<u8> for ModemStatusCode {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => ModemStatusCode::HardwareReset,
+      0x01 => ModemStatusCode::WatchdogTimerReset,
+      0x02 => ModemStatusCode::JoinedNetwork,
+      0x03 => ModemStatusCode::Disassociated,
+      0x04 => ModemStatusCode::ConfigurationError,
+      0x05 => ModemStatusCode::CoordinatorRealignment,
+      0x06 => ModemStatusCode::CoordinatorStarted,
+      0x07 => ModemStatusCode::NetworkSecurityKeyUpdated,
+      0x0B => ModemStatusCode::NetworkWokeUp,
+      0x0C => ModemStatusCode::NetworkWentToSleep,
+      0x0D => ModemStatusCode::VoltageSupplyLimitExceeded,
+      0x10 => ModemStatusCode::KeyEstablishmentComplete,
+      0x11 => ModemStatusCode::ConfigurationChangedWhileJoining,
+      0x12 => ModemStatusCode::AccessFault,
+      0x13 => ModemStatusCode::FatalStackError,
+      0x14 => ModemStatusCode::PlkeTableInitiated,
+      0x15 => ModemStatusCode::PlkeTableSuccess,
+      0x16 => ModemStatusCode::PlkeTableFull,
+      0x17 => ModemStatusCode::PlkeNotAuthorized,
+      0x18 => ModemStatusCode::PlkeInvalidTrustCenterRequest,
+      0x19 => ModemStatusCode::PlkeTrustCenterUpdateFailed,
+      0x1A => ModemStatusCode::PlkeBadEuiAddress,
+      0x1B => ModemStatusCode::PlkeLinkKeyRejected,
+      0x1C => ModemStatusCode::PlkeUpdateOccurred,
+      0x1D => ModemStatusCode::PlkeLinkKeyTableCleared,
+      0x1E => ModemStatusCode::FrequencyAgilityChannelChange,
+      0x1F => ModemStatusCode::NoJoinableBeacons,
+      0x20 => ModemStatusCode::TokenSpaceRecovered,
+      0x21 => ModemStatusCode::TokenSpaceUnrecoverable,
+      0x22 => ModemStatusCode::TokenSpaceCorrupted,
+      0x3B => ModemStatusCode::SecureSessionEstablished,
+      0x3C => ModemStatusCode::SecureSessionEnded,
+      0x3D => ModemStatusCode::SecureSessionAuthenticationFailed,
+      0x3E => ModemStatusCode::PanIdConflictDetected,
+      0x3F => ModemStatusCode::PanIdChangedByCoordinator,
+      0x40 => ModemStatusCode::RouterPanIdChanged,
+      0x42 => ModemStatusCode::NetworkWatchdogExpired,
+      0x43 => ModemStatusCode::JoinWindowOpened,
+      0x44 => ModemStatusCode::JoinWindowClosed,
+      0x45 => ModemStatusCode::NetworkKeyRotationInitiated,
+      0x80..=0xFF => ModemStatusCode::StackError(value),
+      _ => ModemStatusCode::Other(value)
+    }
+  }
+}
+
+impl From<ModemStatusCode> for u8 {
+  fn from(code: ModemStatusCode) -> u8 {
+    match code {
+      ModemStatusCode::HardwareReset => 0x00,
+      ModemStatusCode::WatchdogTimerReset => 0x01,
+      ModemStatusCode::JoinedNetwork => 0x02,
+      ModemStatusCode::Disassociated => 0x03,
+      ModemStatusCode::ConfigurationError => 0x04,
+      ModemStatusCode::CoordinatorRealignment => 0x05,
+      ModemStatusCode::CoordinatorStarted => 0x06,
+      ModemStatusCode::NetworkSecurityKeyUpdated => 0x07,
+      ModemStatusCode::NetworkWokeUp => 0x0B,
+      ModemStatusCode::NetworkWentToSleep => 0x0C,
+      ModemStatusCode::VoltageSupplyLimitExceeded => 0x0D,
+      ModemStatusCode::KeyEstablishmentComplete => 0x10,
+      ModemStatusCode::ConfigurationChangedWhileJoining => 0x11,
+      ModemStatusCode::AccessFault => 0x12,
+      ModemStatusCode::FatalStackError => 0x13,
+      ModemStatusCode::PlkeTableInitiated => 0x14,
+      ModemStatusCode::PlkeTableSuccess => 0x15,
+      ModemStatusCode::PlkeTableFull => 0x16,
+      ModemStatusCode::PlkeNotAuthorized => 0x17,
+      ModemStatusCode::PlkeInvalidTrustCenterRequest => 0x18,
+      ModemStatusCode::PlkeTrustCenterUpdateFailed => 0x19,
+      ModemStatusCode::PlkeBadEuiAddress => 0x1A,
+      ModemStatusCode::PlkeLinkKeyRejected => 0x1B,
+      ModemStatusCode::PlkeUpdateOccurred => 0x1C,
+      ModemStatusCode::PlkeLinkKeyTableCleared => 0x1D,
+      ModemStatusCode::FrequencyAgilityChannelChange => 0x1E,
+      ModemStatusCode::NoJoinableBeacons => 0x1F,
+      ModemStatusCode::TokenSpaceRecovered => 0x20,
+      ModemStatusCode::TokenSpaceUnrecoverable => 0x21,
+      ModemStatusCode::TokenSpaceCorrupted => 0x22,
+      ModemStatusCode::SecureSessionEstablished => 0x3B,
+      ModemStatusCode::SecureSessionEnded => 0x3C,
+      ModemStatusCode::SecureSessionAuthenticationFailed => 0x3D,
+      ModemStatusCode::PanIdConflictDetected => 0x3E,
+      ModemStatusCode::PanIdChangedByCoordinator => 0x3F,
+      ModemStatusCode::RouterPanIdChanged => 0x40,
+      ModemStatusCode::NetworkWatchdogExpired => 0x42,
+      ModemStatusCode::JoinWindowOpened => 0x43,
+      ModemStatusCode::JoinWindowClosed => 0x44,
+      ModemStatusCode::NetworkKeyRotationInitiated => 0x45,
+      ModemStatusCode::StackError(value) => value,
+      ModemStatusCode::Other(value) => value
+    }
+  }
+}
+
+/// Sent by the XBee whenever a notable event such as a reset or a change
+/// in network membership happens.
+pub struct ModemStatus {
+  pub status: ModemStatusCode
+}
+
+impl <'a> Response<'a, ModemStatus> for ModemStatus {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 2 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    Ok(Self { status: ModemStatusCode::from(buffer[1]) })
+  }
+}
+
+#[test]
+fn test_modem_status_parse() {
+  let buffer: [u8; 2] = [0x8A, 0x06];
+  let response = ModemStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.status, ModemStatusCode::CoordinatorStarted);
+}
+
+#[test]
+fn test_modem_status_unknown_codes() {
+  assert_eq!(ModemStatusCode::from(0x2F), ModemStatusCode::Other(0x2F));
+  assert_eq!(ModemStatusCode::from(0x91), ModemStatusCode::StackError(0x91));
+}
+
+#[test]
+fn test_modem_status_round_trip() {
+  for value in 0..=255u8 {
+    assert_eq!(u8::from(ModemStatusCode::from(value)), value);
+  }
+}
+
+#[test]
+fn test_modem_status_bad_size() {
+  let buffer: [u8; 3] = [0x8A, 0x06, 0x00];
+  let response = ModemStatus::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
@@ -0,0 +1,186 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+use core::convert::TryFrom;
+
+const COMMAND_ID: u8 = 0x95;
+
+/// The role a node plays in the network.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeviceType {
+  Coordinator = 0,
+  Router = 1,
+  EndDevice = 2
+}
+
+impl TryFrom<u8> for DeviceType {
+  type Error = ResponseError;
+
+  fn try_from(value: u8) -> Result<Self, ResponseError> {
+    match value {
+      0 => Ok(DeviceType::Coordinator),
+      1 => Ok(DeviceType::Router),
+      2 => Ok(DeviceType::EndDevice),
+      _ => Err(ResponseError::EnumComparisonError)
+    }
+  }
+}
+
+/// A node on the network as described by a Node Identification Indicator
+/// or by one of the responses to an `ND` AT command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemoteNode<'a> {
+  pub network_address: u16,
+  pub address: u64,
+  pub node_identifier: &'a str,
+  pub parent_network_address: u16,
+  pub device_type: DeviceType,
+  /// The source event for a Node Identification Indicator: 1 for a
+  /// pushbutton press, 2 for a join and 3 for a power cycle. Reserved for
+  /// `ND` responses.
+  pub status: u8,
+  pub profile_id: u16,
+  pub manufacturer_id: u16
+}
+
+impl <'a> RemoteNode<'a> {
+  /// Parses the node description shared by both frames, starting at the
+  /// 16-bit network address. Optional trailing fields such as `DD` and the
+  /// RSSI are ignored.
+  pub fn parse(buffer: &'a [u8]) -> Result<Self, ResponseError> {
+    if buffer.len() < 11 { return Err(ResponseError::SizeIncorrectError); }
+
+    let network_address: u16 = buffer[0] as u16 * 256 + buffer[1] as u16;
+    let address = buffer_to_u64(&buffer[2..10]);
+
+    // The node identifier is terminated by a null byte
+    let identifier_length = match buffer[10..].iter().position(|byte| *byte == 0) {
+      Some(length) => length,
+      None => return Err(ResponseError::SizeIncorrectError)
+    };
+    let node_identifier = core::str::from_utf8(&buffer[10..10 + identifier_length])
+      .map_err(|_| ResponseError::EncodingError)?;
+
+    let rest = &buffer[11 + identifier_length..];
+    if rest.len() < 8 { return Err(ResponseError::SizeIncorrectError); }
+
+    Ok(Self {
+      network_address,
+      address,
+      node_identifier,
+      parent_network_address: rest[0] as u16 * 256 + rest[1] as u16,
+      device_type: DeviceType::try_from(rest[2])?,
+      status: rest[3],
+      profile_id: rest[4] as u16 * 256 + rest[5] as u16,
+      manufacturer_id: rest[6] as u16 * 256 + rest[7] as u16
+    })
+  }
+}
+
+/// Sent when a node joins the network or its commissioning button is
+/// pressed.
+pub struct NodeIdentification<'a> {
+  pub sender: u64,
+  pub sender_network_address: u16,
+  pub packet_acknowledged: bool,
+  pub packet_broadcast: bool,
+  pub node: RemoteNode<'a>
+}
+
+impl <'a> Response<'a, NodeIdentification<'a>> for NodeIdentification<'a> {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<NodeIdentification<'a>, ResponseError> {
+    if buffer.len() < 12 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let sender = buffer_to_u64(&buffer[1..9]);
+    let sender_network_address: u16 = buffer[9] as u16 * 256 + buffer[10] as u16;
+    let packet_acknowledged = buffer[11] & 0x01 != 0;
+    let packet_broadcast = buffer[11] & 0x02 != 0;
+    let node = RemoteNode::parse(&buffer[12..])?;
+
+    Ok(Self { sender, sender_network_address, packet_acknowledged, packet_broadcast, node })
+  }
+}
+
+#[test]
+fn test_node_identification_parse() {
+  let buffer: [u8; 34] = [
+    0x95,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    0x02,
+    0x7D, 0x84,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x20, 0x00,
+    0xFF, 0xFE,
+    0x01,
+    0x01,
+    0xC1, 0x05,
+    0x10, 0x1E,
+    0x00, 0x00
+  ];
+  let response = NodeIdentification::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.sender, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.sender_network_address, 0x7D84);
+  assert!(response.packet_broadcast);
+  assert_eq!(response.node.network_address, 0x7D84);
+  assert_eq!(response.node.address, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.node.node_identifier, " ");
+  assert_eq!(response.node.parent_network_address, 0xFFFE);
+  assert_eq!(response.node.device_type, DeviceType::Router);
+  assert_eq!(response.node.status, 0x01);
+  assert_eq!(response.node.profile_id, 0xC105);
+  assert_eq!(response.node.manufacturer_id, 0x101E);
+}
+
+#[test]
+fn test_node_discovery_parse() {
+  // The data of an ND response, including the optional RSSI
+  let buffer: [u8; 26] = [
+    0x12, 0x34,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27,
+    b'S', b'E', b'N', b'S', b'O', b'R', 0x00,
+    0x00, 0x00,
+    0x02,
+    0x00,
+    0xC1, 0x05,
+    0x10, 0x1E,
+    0x28
+  ];
+  let node = RemoteNode::parse(&buffer[..]).unwrap();
+
+  assert_eq!(node.network_address, 0x1234);
+  assert_eq!(node.address, 0x0013_A200_400A_0127);
+  assert_eq!(node.node_identifier, "SENSOR");
+  assert_eq!(node.parent_network_address, 0x0000);
+  assert_eq!(node.device_type, DeviceType::EndDevice);
+  assert_eq!(node.profile_id, 0xC105);
+  assert_eq!(node.manufacturer_id, 0x101E);
+}
+
+#[test]
+fn test_node_discovery_errors() {
+  // Missing the null terminator
+  let buffer: [u8; 13] = [
+    0x12, 0x34,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27,
+    b'S', b'E', b'N'
+  ];
+  assert_eq!(RemoteNode::parse(&buffer[..]), Err(ResponseError::SizeIncorrectError));
+
+  // Unknown device type
+  let buffer: [u8; 19] = [
+    0x12, 0x34,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x0A, 0x01, 0x27,
+    0x00,
+    0x00, 0x00,
+    0x07,
+    0x00,
+    0xC1, 0x05,
+    0x10, 0x1E
+  ];
+  assert_eq!(RemoteNode::parse(&buffer[..]), Err(ResponseError::EnumComparisonError));
+}
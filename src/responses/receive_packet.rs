@@ -1,30 +1,17 @@
-use super::{ Response, ResponseError };
+use super::{ Response, ResponseError, buffer_to_u64 };
 
 const COMMAND_ID: u8 = 0x90;
 
-#[allow(dead_code)]
 pub struct ZigbeeReceivePacket<'a> {
-  destination: u64,
-  network_address: u16,
-  packet_acknowledged: bool,
-  packet_broadcast: bool,
-  packet_encrypted: bool,
-  packet_end_device: bool,
+  pub destination: u64,
+  pub network_address: u16,
+  pub packet_acknowledged: bool,
+  pub packet_broadcast: bool,
+  pub packet_encrypted: bool,
+  pub packet_end_device: bool,
   pub data: &'a [u8]
 }
 
-fn buffer_to_u64(buffer: &[u8]) -> u64 {
-  let mut output: u64 = 0;
-  let mut bitshift = 56;
-
-  for x in 0..8 {
-    output += (buffer[x] as u64) << bitshift;
-    bitshift -= 8;
-  }
-
-  output
-}
-
 impl <'a> Response<'a, ZigbeeReceivePacket<'a>> for ZigbeeReceivePacket<'a> {
   fn respond_to(id: u8) -> bool {
     COMMAND_ID == id
@@ -67,9 +54,9 @@ fn test_at_command_response_parse() {
 
   assert_eq!(response.destination, 0x0013_A200_4052_2BAA);
   assert_eq!(response.network_address, 0x7D84);
-  assert_eq!(response.packet_acknowledged, true);
-  assert_eq!(response.packet_broadcast, false);
-  assert_eq!(response.packet_broadcast, false);
-  assert_eq!(response.packet_broadcast, false);
+  assert!(response.packet_acknowledged);
+  assert!(!response.packet_broadcast);
+  assert!(!response.packet_encrypted);
+  assert!(!response.packet_end_device);
   assert_eq!(response.data, &buffer[12..]);
 }
@@ -0,0 +1,67 @@
+use super::{ Response, ResponseError };
+
+const COMMAND_ID: u8 = 0xA4;
+
+/// The result of a Register Joining Device request. Codes that are not
+/// listed here are kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegistrationStatus {
+  Success,
+  KeyTooLong,
+  AddressNotFound,
+  InvalidKey,
+  InvalidAddress,
+  KeyTableFull,
+  SecurityDataInvalid,
+  Other(u8)
+}
+
+impl From<u8> for RegistrationStatus {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => RegistrationStatus::Success,
+      0x01 => RegistrationStatus::KeyTooLong,
+      0xB1 => RegistrationStatus::AddressNotFound,
+      0xB2 => RegistrationStatus::InvalidKey,
+      0xB3 => RegistrationStatus::InvalidAddress,
+      0xB4 => RegistrationStatus::KeyTableFull,
+      0xBD => RegistrationStatus::SecurityDataInvalid,
+      other => RegistrationStatus::Other(other)
+    }
+  }
+}
+
+pub struct RegisterJoiningDeviceStatus {
+  pub frame_id: u8,
+  pub status: RegistrationStatus
+}
+
+impl <'a> Response<'a, RegisterJoiningDeviceStatus> for RegisterJoiningDeviceStatus {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 3 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    Ok(Self { frame_id: buffer[1], status: RegistrationStatus::from(buffer[2]) })
+  }
+}
+
+#[test]
+fn test_register_joining_device_status_parse() {
+  let buffer: [u8; 3] = [0xA4, 0x01, 0xB4];
+  let response = RegisterJoiningDeviceStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.frame_id, 0x01);
+  assert_eq!(response.status, RegistrationStatus::KeyTableFull);
+}
+
+#[test]
+fn test_register_joining_device_status_bad_size() {
+  let buffer: [u8; 2] = [0xA4, 0x01];
+  let response = RegisterJoiningDeviceStatus::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
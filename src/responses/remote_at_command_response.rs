@@ -0,0 +1,86 @@
+use super::{ Response, ResponseError, AtCommandResponseStatus, buffer_to_u64 };
+use core::convert::TryFrom;
+
+const COMMAND_ID: u8 = 0x97;
+
+/// The answer to a `RemoteAtCommand`, sent on behalf of the remote device.
+pub struct RemoteAtCommandResponse<'a> {
+  pub frame_id: u8,
+  pub source: u64,
+  pub network_address: u16,
+  pub at_command: [u8; 2],
+  pub command_status: AtCommandResponseStatus,
+  pub command_data: Option<&'a [u8]>,
+}
+
+impl <'a> Response<'a, RemoteAtCommandResponse<'a>> for RemoteAtCommandResponse<'a> {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<Self, ResponseError> {
+    if buffer.len() < 15 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let frame_id = buffer[1];
+    let source = buffer_to_u64(&buffer[2..10]);
+    let network_address: u16 = buffer[10] as u16 * 256 + buffer[11] as u16;
+    let at_command = [buffer[12], buffer[13]];
+    let command_status = AtCommandResponseStatus::try_from(buffer[14])?;
+
+    let command_data = match buffer.len() {
+      15 => None,
+      _ => Some(&buffer[15..])
+    };
+
+    Ok(Self { frame_id, source, network_address, at_command, command_status, command_data })
+  }
+}
+
+#[test]
+fn test_remote_at_command_response_parse() {
+  let buffer: [u8; 17] = [
+    0x97, 0x27,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x7D, 0x84,
+    0x53, 0x4C,
+    0x00,
+    0x40, 0x52
+  ];
+  let response = RemoteAtCommandResponse::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.frame_id, 0x27);
+  assert_eq!(response.source, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.network_address, 0x7D84);
+  assert_eq!(response.at_command, [b'S', b'L']);
+  assert_eq!(response.command_status, AtCommandResponseStatus::Ok);
+  assert_eq!(response.command_data, Some(&[0x40, 0x52][..]));
+}
+
+#[test]
+fn test_remote_at_command_response_tx_failure() {
+  let buffer: [u8; 15] = [
+    0x97, 0x27,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0xFF, 0xFE,
+    0x53, 0x4C,
+    0x04
+  ];
+  let response = RemoteAtCommandResponse::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.command_status, AtCommandResponseStatus::TxFailure);
+  assert_eq!(response.command_data, None);
+}
+
+#[test]
+fn test_remote_at_command_response_bad_size() {
+  let buffer: [u8; 14] = [
+    0x97, 0x27,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0xFF, 0xFE,
+    0x53, 0x4C
+  ];
+  let response = RemoteAtCommandResponse::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
@@ -0,0 +1,134 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0x8D;
+const DATA_LENGTH: u8 = 0x27;
+
+/// Why a DigiMesh Route Information frame was sent. Events that are not
+/// listed here, such as ones added by newer firmware, are kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RouteInformationEvent {
+  /// An acknowledgement failed on a transmission sent with NACK enabled.
+  Nack,
+  /// A hop of a transmission sent with trace route enabled.
+  TraceRoute,
+  Other(u8)
+}
+
+impl From<u8> for RouteInformationEvent {
+  fn from(value: u8) -> Self {
+    match value {
+      0x11 => RouteInformationEvent::Nack,
+      0x12 => RouteInformationEvent::TraceRoute,
+      other => RouteInformationEvent::Other(other)
+    }
+  }
+}
+
+/// Describes one hop of a DigiMesh transmission, from the `responder` that
+/// relayed the packet to the `receiver` it was relayed to. `timestamp` is
+/// the responder's system timer in microseconds.
+pub struct RouteInformation {
+  pub event: RouteInformationEvent,
+  pub timestamp: u32,
+  pub ack_timeout_count: u8,
+  pub tx_blocked_count: u8,
+  pub destination: u64,
+  pub source: u64,
+  pub responder: u64,
+  pub receiver: u64
+}
+
+impl <'a> Response<'a, RouteInformation> for RouteInformation {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    // Newer firmware may append fields, which are ignored
+    if buffer.len() < 3 + DATA_LENGTH as usize { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+    if buffer[2] < DATA_LENGTH { return Err(ResponseError::SizeIncorrectError); }
+
+    let event = RouteInformationEvent::from(buffer[1]);
+    let timestamp = buffer[3..7].iter().fold(0, |timestamp, byte| (timestamp << 8) | *byte as u32);
+
+    // buffer[9] is reserved
+    Ok(Self {
+      event,
+      timestamp,
+      ack_timeout_count: buffer[7],
+      tx_blocked_count: buffer[8],
+      destination: buffer_to_u64(&buffer[10..18]),
+      source: buffer_to_u64(&buffer[18..26]),
+      responder: buffer_to_u64(&buffer[26..34]),
+      receiver: buffer_to_u64(&buffer[34..42])
+    })
+  }
+}
+
+#[cfg(test)]
+const EXAMPLE_ROUTE_INFORMATION: [u8; 42] = [
+  0x8D,
+  0x12,
+  0x27,
+  0x9C, 0x93, 0x81, 0x7F,
+  0x00,
+  0x00,
+  0x00,
+  0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0xAA, 0xAA,
+  0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0xDD, 0xDD,
+  0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0xBB, 0xBB,
+  0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0xCC, 0xCC
+];
+
+#[test]
+fn test_route_information_parse() {
+  let response = RouteInformation::parse(&EXAMPLE_ROUTE_INFORMATION[..]).unwrap();
+
+  assert_eq!(response.event, RouteInformationEvent::TraceRoute);
+  assert_eq!(response.timestamp, 0x9C93_817F);
+  assert_eq!(response.ack_timeout_count, 0);
+  assert_eq!(response.tx_blocked_count, 0);
+  assert_eq!(response.destination, 0x0013_A200_4052_AAAA);
+  assert_eq!(response.source, 0x0013_A200_4052_DDDD);
+  assert_eq!(response.responder, 0x0013_A200_4052_BBBB);
+  assert_eq!(response.receiver, 0x0013_A200_4052_CCCC);
+}
+
+#[test]
+fn test_route_information_trailing_byte() {
+  let mut buffer: [u8; 43] = [0; 43];
+  buffer[..42].copy_from_slice(&EXAMPLE_ROUTE_INFORMATION);
+  buffer[2] = 0x28;
+  buffer[42] = 0x5A;
+
+  let response = RouteInformation::parse(&buffer[..]).unwrap();
+  assert_eq!(response.event, RouteInformationEvent::TraceRoute);
+  assert_eq!(response.receiver, 0x0013_A200_4052_CCCC);
+}
+
+#[test]
+fn test_route_information_errors() {
+  let response = RouteInformation::parse(&EXAMPLE_ROUTE_INFORMATION[..41]);
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+
+  let mut buffer = EXAMPLE_ROUTE_INFORMATION;
+  buffer[2] = 0x26;
+  let response = RouteInformation::parse(&buffer[..]);
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+
+  let mut buffer = EXAMPLE_ROUTE_INFORMATION;
+  buffer[0] = 0x8E;
+  let response = RouteInformation::parse(&buffer[..]);
+  assert_eq!(response.err(), Some(ResponseError::IdError));
+}
+
+#[test]
+fn test_route_information_unknown_event() {
+  let mut buffer = EXAMPLE_ROUTE_INFORMATION;
+  buffer[1] = 0x13;
+  let response = RouteInformation::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.event, RouteInformationEvent::Other(0x13));
+  assert_eq!(response.destination, 0x0013_A200_4052_AAAA);
+}
@@ -0,0 +1,105 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0xA1;
+
+/// Sent to a concentrator when a packet arrives that recorded the route it
+/// took through the network.
+pub struct RouteRecordIndicator<'a> {
+  pub source: u64,
+  pub network_address: u16,
+  pub packet_acknowledged: bool,
+  pub packet_broadcast: bool,
+  hops: &'a [u8]
+}
+
+impl <'a> RouteRecordIndicator<'a> {
+  /// The number of intermediate hops the packet took.
+  pub fn hop_count(&self) -> usize {
+    self.hops.len() / 2
+  }
+
+  /// The 16-bit addresses of the intermediate hops, starting with the
+  /// neighbor of the source.
+  pub fn hops(&self) -> Hops<'a> {
+    Hops { hops: self.hops }
+  }
+}
+
+/// An iterator over the 16-bit addresses in a route record.
+pub struct Hops<'a> {
+  hops: &'a [u8]
+}
+
+impl <'a> Iterator for Hops<'a> {
+  type Item = u16;
+
+  fn next(&mut self) -> Option<u16> {
+    if self.hops.len() < 2 { return None; }
+
+    let hop = self.hops[0] as u16 * 256 + self.hops[1] as u16;
+    self.hops = &self.hops[2..];
+
+    Some(hop)
+  }
+}
+
+impl <'a> Response<'a, RouteRecordIndicator<'a>> for RouteRecordIndicator<'a> {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<RouteRecordIndicator<'a>, ResponseError> {
+    if buffer.len() < 13 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let hop_count = buffer[12] as usize;
+    if buffer.len() != 13 + 2 * hop_count { return Err(ResponseError::SizeIncorrectError); }
+
+    Ok(Self {
+      source: buffer_to_u64(&buffer[1..9]),
+      network_address: buffer[9] as u16 * 256 + buffer[10] as u16,
+      packet_acknowledged: buffer[11] & 0x01 != 0,
+      packet_broadcast: buffer[11] & 0x02 != 0,
+      hops: &buffer[13..]
+    })
+  }
+}
+
+#[test]
+fn test_route_record_parse() {
+  let buffer: [u8; 19] = [
+    0xA1,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x33, 0x44,
+    0x01,
+    0x03,
+    0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF
+  ];
+  let response = RouteRecordIndicator::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.network_address, 0x3344);
+  assert!(response.packet_acknowledged);
+  assert_eq!(response.hop_count(), 3);
+
+  let mut hops = response.hops();
+  assert_eq!(hops.next(), Some(0xAABB));
+  assert_eq!(hops.next(), Some(0xCCDD));
+  assert_eq!(hops.next(), Some(0xEEFF));
+  assert_eq!(hops.next(), None);
+}
+
+#[test]
+fn test_route_record_bad_size() {
+  let buffer: [u8; 17] = [
+    0xA1,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x33, 0x44,
+    0x01,
+    0x03,
+    0xAA, 0xBB, 0xCC, 0xDD
+  ];
+  let response = RouteRecordIndicator::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
@@ -0,0 +1,93 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const RX_PACKET_64_ID: u8 = 0x80;
+const RX_PACKET_16_ID: u8 = 0x81;
+
+/// An 802.15.4 packet received from a sender using its 64-bit address.
+/// `rssi` is the received signal strength in -dBm.
+pub struct RxPacket64<'a> {
+  pub source: u64,
+  pub rssi: u8,
+  pub address_broadcast: bool,
+  pub pan_broadcast: bool,
+  pub data: &'a [u8]
+}
+
+impl <'a> Response<'a, RxPacket64<'a>> for RxPacket64<'a> {
+  fn respond_to(id: u8) -> bool {
+    RX_PACKET_64_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<RxPacket64<'a>, ResponseError> {
+    if buffer.len() < 11 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != RX_PACKET_64_ID { return Err(ResponseError::IdError); }
+
+    Ok(Self {
+      source: buffer_to_u64(&buffer[1..9]),
+      rssi: buffer[9],
+      address_broadcast: buffer[10] & 0x02 != 0,
+      pan_broadcast: buffer[10] & 0x04 != 0,
+      data: &buffer[11..]
+    })
+  }
+}
+
+/// An 802.15.4 packet received from a sender using its 16-bit address.
+/// `rssi` is the received signal strength in -dBm.
+pub struct RxPacket16<'a> {
+  pub source: u16,
+  pub rssi: u8,
+  pub address_broadcast: bool,
+  pub pan_broadcast: bool,
+  pub data: &'a [u8]
+}
+
+impl <'a> Response<'a, RxPacket16<'a>> for RxPacket16<'a> {
+  fn respond_to(id: u8) -> bool {
+    RX_PACKET_16_ID == id
+  }
+
+  fn parse(buffer: &'a [u8]) -> Result<RxPacket16<'a>, ResponseError> {
+    if buffer.len() < 5 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != RX_PACKET_16_ID { return Err(ResponseError::IdError); }
+
+    Ok(Self {
+      source: buffer[1] as u16 * 256 + buffer[2] as u16,
+      rssi: buffer[3],
+      address_broadcast: buffer[4] & 0x02 != 0,
+      pan_broadcast: buffer[4] & 0x04 != 0,
+      data: &buffer[5..]
+    })
+  }
+}
+
+#[test]
+fn test_rx_packet_64_parse() {
+  let buffer: [u8; 13] = [
+    0x80,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0x2C,
+    0x02,
+    0x52, 0x78
+  ];
+  let response = RxPacket64::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.rssi, 0x2C);
+  assert!(response.address_broadcast);
+  assert!(!response.pan_broadcast);
+  assert_eq!(response.data, [0x52, 0x78]);
+}
+
+#[test]
+fn test_rx_packet_16_parse() {
+  let buffer: [u8; 7] = [0x81, 0x12, 0x34, 0x28, 0x00, 0x52, 0x78];
+  let response = RxPacket16::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x1234);
+  assert_eq!(response.rssi, 0x28);
+  assert_eq!(response.data, [0x52, 0x78]);
+
+  assert_eq!(RxPacket16::parse(&buffer[..4]).err(), Some(ResponseError::SizeIncorrectError));
+  assert_eq!(RxPacket64::parse(&buffer[..]).err(), Some(ResponseError::SizeIncorrectError));
+}
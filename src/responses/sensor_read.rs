@@ -0,0 +1,174 @@
+use super::{ Response, ResponseError, buffer_to_u64 };
+
+const COMMAND_ID: u8 = 0x94;
+const AD_SENSOR_READ: u8 = 0x01;
+const TEMPERATURE_SENSOR_READ: u8 = 0x02;
+const NOT_PRESENT: u16 = 0xFFFF;
+
+// Indices of the A/D channels on a Digi light/temperature/humidity adapter
+const LIGHT_CHANNEL: usize = 1;
+const HUMIDITY_CHANNEL: usize = 3;
+
+/// A reading from a Digi 1-Wire sensor adapter attached to a remote XBee.
+pub struct XBeeSensorRead {
+  pub source: u64,
+  pub network_address: u16,
+  pub packet_acknowledged: bool,
+  pub packet_broadcast: bool,
+  /// Which sensors were read, see `has_ad_sensor` and `has_temperature_sensor`.
+  pub sensors: u8,
+  /// The four raw A/D values, 0xFFFF if not present.
+  pub ad_values: [u16; 4],
+  /// The raw reading of the digital thermometer, 0xFFFF if not present.
+  pub temperature: u16
+}
+
+impl XBeeSensorRead {
+  /// Whether the A/D channels of the adapter were read.
+  pub fn has_ad_sensor(&self) -> bool {
+    self.sensors & AD_SENSOR_READ != 0
+  }
+
+  /// Whether the digital thermometer of the adapter was read.
+  pub fn has_temperature_sensor(&self) -> bool {
+    self.sensors & TEMPERATURE_SENSOR_READ != 0 && self.temperature != NOT_PRESENT
+  }
+
+  /// The digital thermometer reading in degrees Celsius. The sensor reports
+  /// a 12-bit two's complement value in sixteenths of a degree.
+  pub fn temperature_celsius(&self) -> Option<f32> {
+    if !self.has_temperature_sensor() { return None; }
+
+    let raw = self.temperature & 0x0FFF;
+    let sixteenths = if raw < 2048 { raw as i32 } else { raw as i32 - 4096 };
+
+    Some(sixteenths as f32 / 16.0)
+  }
+
+  /// A/D channel `channel` in millivolts. The adapter reports readings
+  /// that are scaled by 5.1 mV per count.
+  pub fn millivolts(&self, channel: usize) -> Option<f32> {
+    if !self.has_ad_sensor() || channel >= 4 { return None; }
+    if self.ad_values[channel] == NOT_PRESENT { return None; }
+
+    Some(self.ad_values[channel] as f32 * 5.1)
+  }
+
+  /// The light level in lux from A/D channel 1 of a light sensor adapter,
+  /// whose output is 1 mV per lux.
+  pub fn light_lux(&self) -> Option<f32> {
+    self.millivolts(LIGHT_CHANNEL)
+  }
+
+  /// The relative humidity in percent from A/D channel 3 of a humidity
+  /// sensor adapter. If the digital thermometer was read as well, the value
+  /// is compensated for the temperature.
+  pub fn relative_humidity(&self) -> Option<f32> {
+    let millivolts = self.millivolts(HUMIDITY_CHANNEL)?;
+    let humidity = ((millivolts * 108.2 / 33.2) / 5000.0 - 0.16) / 0.0062;
+
+    match self.temperature_celsius() {
+      Some(celsius) => Some(humidity / (1.0546 - 0.00216 * celsius)),
+      None => Some(humidity)
+    }
+  }
+}
+
+impl <'a> Response<'a, XBeeSensorRead> for XBeeSensorRead {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 23 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let source = buffer_to_u64(&buffer[1..9]);
+    let network_address: u16 = buffer[9] as u16 * 256 + buffer[10] as u16;
+    let packet_acknowledged = buffer[11] & 0x01 != 0;
+    let packet_broadcast = buffer[11] & 0x02 != 0;
+    let sensors = buffer[12];
+
+    let mut ad_values: [u16; 4] = [0; 4];
+    for (index, value) in ad_values.iter_mut().enumerate() {
+      *value = buffer[13 + 2 * index] as u16 * 256 + buffer[14 + 2 * index] as u16;
+    }
+
+    let temperature: u16 = buffer[21] as u16 * 256 + buffer[22] as u16;
+
+    Ok(Self {
+      source,
+      network_address,
+      packet_acknowledged,
+      packet_broadcast,
+      sensors,
+      ad_values,
+      temperature
+    })
+  }
+}
+
+#[test]
+fn test_sensor_read_parse() {
+  // A light/temperature/humidity adapter with every sensor present
+  let buffer: [u8; 23] = [
+    0x94,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0xDD, 0x6C,
+    0x01,
+    0x03,
+    0x00, 0x02, 0x00, 0xCE, 0x00, 0xEA, 0x00, 0x52,
+    0x01, 0x6A
+  ];
+  let response = XBeeSensorRead::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.source, 0x0013_A200_4052_2BAA);
+  assert_eq!(response.network_address, 0xDD6C);
+  assert!(response.packet_acknowledged);
+  assert!(response.has_ad_sensor());
+  assert!(response.has_temperature_sensor());
+  assert_eq!(response.ad_values, [0x0002, 0x00CE, 0x00EA, 0x0052]);
+  assert_eq!(response.temperature, 0x016A);
+
+  assert_eq!(response.temperature_celsius(), Some(22.625));
+  assert_eq!(response.millivolts(1), Some(0xCE as f32 * 5.1));
+  assert_eq!(response.light_lux(), response.millivolts(1));
+
+  let humidity = response.relative_humidity().unwrap();
+  assert!(humidity > 18.0 && humidity < 18.1);
+}
+
+#[test]
+fn test_sensor_read_negative_temperature() {
+  let buffer: [u8; 23] = [
+    0x94,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0xDD, 0x6C,
+    0x01,
+    0x02,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x0F, 0x5E
+  ];
+  let response = XBeeSensorRead::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.temperature_celsius(), Some(-10.125));
+  assert!(!response.has_ad_sensor());
+  assert_eq!(response.millivolts(0), None);
+  assert_eq!(response.relative_humidity(), None);
+}
+
+#[test]
+fn test_sensor_read_bad_size() {
+  let buffer: [u8; 22] = [
+    0x94,
+    0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+    0xDD, 0x6C,
+    0x01,
+    0x03,
+    0x00, 0x02, 0x00, 0xCE, 0x00, 0xEA, 0x00, 0x52,
+    0x01
+  ];
+  let response = XBeeSensorRead::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
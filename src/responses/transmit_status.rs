@@ -0,0 +1,159 @@
+use super::{ Response, ResponseError };
+
+const COMMAND_ID: u8 = 0x8B;
+
+/// The delivery status codes a Zigbee Transmit Status frame can report.
+/// Codes that are not listed here are kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeliveryStatus {
+  Success,
+  MacAckFailure,
+  CcaFailure,
+  StackNotReady,
+  PhysicalError,
+  InvalidDestinationEndpoint,
+  NoBuffers,
+  NetworkAckFailure,
+  NotJoinedToNetwork,
+  SelfAddressed,
+  AddressNotFound,
+  RouteNotFound,
+  BroadcastRelayNotHeard,
+  InvalidBindingTableIndex,
+  ResourceError,
+  ApsBroadcast,
+  ApsUnicastWithoutEncryption,
+  InternalResourceError,
+  ResourceErrorNoBuffers,
+  NoSecureSession,
+  EncryptionFailure,
+  PayloadTooLarge,
+  IndirectMessageUnrequested,
+  Other(u8)
+}
+
+impl From<u8> for DeliveryStatus {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => DeliveryStatus::Success,
+      0x01 => DeliveryStatus::MacAckFailure,
+      0x02 => DeliveryStatus::CcaFailure,
+      0x03 => DeliveryStatus::StackNotReady,
+      0x04 => DeliveryStatus::PhysicalError,
+      0x15 => DeliveryStatus::InvalidDestinationEndpoint,
+      0x18 => DeliveryStatus::NoBuffers,
+      0x21 => DeliveryStatus::NetworkAckFailure,
+      0x22 => DeliveryStatus::NotJoinedToNetwork,
+      0x23 => DeliveryStatus::SelfAddressed,
+      0x24 => DeliveryStatus::AddressNotFound,
+      0x25 => DeliveryStatus::RouteNotFound,
+      0x26 => DeliveryStatus::BroadcastRelayNotHeard,
+      0x2B => DeliveryStatus::InvalidBindingTableIndex,
+      0x2C => DeliveryStatus::ResourceError,
+      0x2D => DeliveryStatus::ApsBroadcast,
+      0x2E => DeliveryStatus::ApsUnicastWithoutEncryption,
+      0x31 => DeliveryStatus::InternalResourceError,
+      0x32 => DeliveryStatus::ResourceErrorNoBuffers,
+      0x34 => DeliveryStatus::NoSecureSession,
+      0x35 => DeliveryStatus::EncryptionFailure,
+      0x74 => DeliveryStatus::PayloadTooLarge,
+      0x75 => DeliveryStatus::IndirectMessageUnrequested,
+      other => DeliveryStatus::Other(other)
+    }
+  }
+}
+
+/// The discovery overhead that was needed to deliver a transmission. Values
+/// that are not listed here, such as extended timeout combined with address
+/// or route discovery, are kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiscoveryStatus {
+  NoDiscoveryOverhead,
+  AddressDiscovery,
+  RouteDiscovery,
+  AddressAndRouteDiscovery,
+  ExtendedTimeoutDiscovery,
+  Other(u8)
+}
+
+impl From<u8> for DiscoveryStatus {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => DiscoveryStatus::NoDiscoveryOverhead,
+      0x01 => DiscoveryStatus::AddressDiscovery,
+      0x02 => DiscoveryStatus::RouteDiscovery,
+      0x03 => DiscoveryStatus::AddressAndRouteDiscovery,
+      0x40 => DiscoveryStatus::ExtendedTimeoutDiscovery,
+      other => DiscoveryStatus::Other(other)
+    }
+  }
+}
+
+/// Sent by the XBee when a `TransmitRequestCommand` with a nonzero frame id
+/// has completed, successfully or not.
+pub struct ZigbeeTransmitStatus {
+  pub frame_id: u8,
+  pub network_address: u16,
+  pub retry_count: u8,
+  pub delivery_status: DeliveryStatus,
+  pub discovery_status: DiscoveryStatus
+}
+
+impl <'a> Response<'a, ZigbeeTransmitStatus> for ZigbeeTransmitStatus {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 7 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    let frame_id = buffer[1];
+    let network_address: u16 = buffer[2] as u16 * 256 + buffer[3] as u16;
+    let retry_count = buffer[4];
+    let delivery_status = DeliveryStatus::from(buffer[5]);
+    let discovery_status = DiscoveryStatus::from(buffer[6]);
+
+    Ok(Self { frame_id, network_address, retry_count, delivery_status, discovery_status })
+  }
+}
+
+#[test]
+fn test_transmit_status_parse() {
+  let buffer: [u8; 7] = [0x8B, 0x47, 0x7D, 0x84, 0x00, 0x00, 0x01];
+  let response = ZigbeeTransmitStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.frame_id, 0x47);
+  assert_eq!(response.network_address, 0x7D84);
+  assert_eq!(response.retry_count, 0);
+  assert_eq!(response.delivery_status, DeliveryStatus::Success);
+  assert_eq!(response.discovery_status, DiscoveryStatus::AddressDiscovery);
+}
+
+#[test]
+fn test_transmit_status_failure() {
+  let buffer: [u8; 7] = [0x8B, 0x01, 0xFF, 0xFD, 0x02, 0x24, 0x00];
+  let response = ZigbeeTransmitStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.network_address, 0xFFFD);
+  assert_eq!(response.retry_count, 2);
+  assert_eq!(response.delivery_status, DeliveryStatus::AddressNotFound);
+  assert_eq!(response.discovery_status, DiscoveryStatus::NoDiscoveryOverhead);
+}
+
+#[test]
+fn test_transmit_status_unknown_codes() {
+  let buffer: [u8; 7] = [0x8B, 0x01, 0xFF, 0xFD, 0x02, 0x99, 0x42];
+  let response = ZigbeeTransmitStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.delivery_status, DeliveryStatus::Other(0x99));
+  assert_eq!(response.discovery_status, DiscoveryStatus::Other(0x42));
+}
+
+#[test]
+fn test_transmit_status_bad_size() {
+  let buffer: [u8; 6] = [0x8B, 0x01, 0xFF, 0xFD, 0x02, 0x00];
+  let response = ZigbeeTransmitStatus::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
@@ -0,0 +1,71 @@
+use super::{ Response, ResponseError };
+
+const COMMAND_ID: u8 = 0x89;
+
+/// The delivery status codes an 802.15.4 TX Status frame can report.
+/// Codes that are not listed here, such as the ones newer firmware
+/// reports, are kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TxDeliveryStatus {
+  Success,
+  NoAcknowledgement,
+  CcaFailure,
+  Purged,
+  Other(u8)
+}
+
+impl From<u8> for TxDeliveryStatus {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => TxDeliveryStatus::Success,
+      0x01 => TxDeliveryStatus::NoAcknowledgement,
+      0x02 => TxDeliveryStatus::CcaFailure,
+      0x03 => TxDeliveryStatus::Purged,
+      other => TxDeliveryStatus::Other(other)
+    }
+  }
+}
+
+/// Reports the outcome of a `TxRequest64` or `TxRequest16`.
+pub struct TxStatus {
+  pub frame_id: u8,
+  pub delivery_status: TxDeliveryStatus
+}
+
+impl <'a> Response<'a, TxStatus> for TxStatus {
+  fn respond_to(id: u8) -> bool {
+    COMMAND_ID == id
+  }
+
+  fn parse(buffer: &[u8]) -> Result<Self, ResponseError> {
+    if buffer.len() != 3 { return Err(ResponseError::SizeIncorrectError); }
+    if buffer[0] != COMMAND_ID { return Err(ResponseError::IdError); }
+
+    Ok(Self { frame_id: buffer[1], delivery_status: TxDeliveryStatus::from(buffer[2]) })
+  }
+}
+
+#[test]
+fn test_tx_status_parse() {
+  let buffer: [u8; 3] = [0x89, 0x01, 0x01];
+  let response = TxStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.frame_id, 0x01);
+  assert_eq!(response.delivery_status, TxDeliveryStatus::NoAcknowledgement);
+}
+
+#[test]
+fn test_tx_status_unknown_status() {
+  let buffer: [u8; 3] = [0x89, 0x01, 0x74];
+  let response = TxStatus::parse(&buffer[..]).unwrap();
+
+  assert_eq!(response.delivery_status, TxDeliveryStatus::Other(0x74));
+}
+
+#[test]
+fn test_tx_status_bad_size() {
+  let buffer: [u8; 2] = [0x89, 0x01];
+  let response = TxStatus::parse(&buffer[..]);
+
+  assert_eq!(response.err(), Some(ResponseError::SizeIncorrectError));
+}
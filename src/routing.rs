@@ -0,0 +1,235 @@
+use super::commands::{ BufferSizeError, CreateSourceRoute };
+use super::responses::RouteRecordIndicator;
+
+#[derive(Clone, Copy)]
+struct Route<const HOPS: usize> {
+  address: u64,
+  network_address: u16,
+  hops: [u16; HOPS],
+  hop_count: usize,
+
+  // The table's clock when the route was last learned or used
+  last_used: u32
+}
+
+/// A fixed capacity table of source routes for up to `NODES` nodes of up to
+/// `HOPS` hops each. It learns routes from `RouteRecordIndicator`s and
+/// produces the `CreateSourceRoute` to send before transmitting to a node.
+pub struct SourceRouteTable<const NODES: usize, const HOPS: usize> {
+  routes: [Option<Route<HOPS>>; NODES],
+
+  // Ticks on every use of a route, so that the least recently used one can
+  // be evicted
+  clock: u32
+}
+
+impl <const NODES: usize, const HOPS: usize> SourceRouteTable<NODES, HOPS> {
+  pub fn new() -> Self {
+    Self { routes: [None; NODES], clock: 0 }
+  }
+
+  /// Stores the route from a route record, replacing any earlier route to
+  /// the same node. When the table is full the route that was least
+  /// recently learned or used is evicted. Fails if the route has more than
+  /// `HOPS` hops.
+  pub fn learn(&mut self, record: &RouteRecordIndicator) -> Result<(), BufferSizeError> {
+    if record.hop_count() > HOPS { return Err(BufferSizeError); }
+
+    let mut route = Route {
+      address: record.source,
+      network_address: record.network_address,
+      hops: [0; HOPS],
+      hop_count: record.hop_count(),
+      last_used: self.tick()
+    };
+
+    for (slot, hop) in route.hops.iter_mut().zip(record.hops()) {
+      *slot = hop;
+    }
+
+    let existing = self.routes.iter().position(|slot| match slot {
+      Some(stored) => stored.address == route.address,
+      None => false
+    });
+
+    let index = match existing.or_else(|| self.routes.iter().position(Option::is_none)) {
+      Some(index) => index,
+      None => match self.least_recently_used() {
+        Some(index) => index,
+        None => return Err(BufferSizeError)
+      }
+    };
+
+    self.routes[index] = Some(route);
+    Ok(())
+  }
+
+  /// The `CreateSourceRoute` for the node with 64-bit address `address`, if
+  /// a route to it is known. This counts as a use of the route.
+  pub fn route_to(&mut self, address: u64) -> Option<CreateSourceRoute<'_>> {
+    let now = self.tick();
+    let route = self.routes.iter_mut().flatten().find(|route| route.address == address)?;
+    route.last_used = now;
+
+    // Route records hold at most 255 hops, so every stored route fits
+    CreateSourceRoute::new(route.address, route.network_address, &route.hops[0..route.hop_count]).ok()
+  }
+
+  /// Removes the route to `address`, for example after a delivery failure.
+  pub fn forget(&mut self, address: u64) {
+    for slot in self.routes.iter_mut() {
+      if let Some(route) = slot {
+        if route.address == address { *slot = None; }
+      }
+    }
+  }
+
+  /// Removes every route, for example after a many-to-one route request.
+  pub fn clear(&mut self) {
+    self.routes = [None; NODES];
+  }
+
+  /// The number of nodes a route is known for.
+  pub fn len(&self) -> usize {
+    self.routes.iter().flatten().count()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  fn tick(&mut self) -> u32 {
+    self.clock = self.clock.wrapping_add(1);
+    self.clock
+  }
+
+  /// The slot whose route has gone unused the longest. Ages are measured
+  /// back from the clock, so they stay correct when it wraps.
+  fn least_recently_used(&self) -> Option<usize> {
+    let clock = self.clock;
+
+    self.routes.iter().enumerate()
+      .filter_map(|(index, slot)| slot.map(|route| (index, clock.wrapping_sub(route.last_used))))
+      .max_by_key(|(_, age)| *age)
+      .map(|(index, _)| index)
+  }
+}
+
+impl <const NODES: usize, const HOPS: usize> Default for SourceRouteTable<NODES, HOPS> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+fn route_record(source: u64, network_address: u16, hops: &[u16], buffer: &mut [u8; 32]) -> usize {
+  buffer[0] = 0xA1;
+  buffer[1..9].copy_from_slice(&source.to_be_bytes());
+  buffer[9] = (network_address >> 8) as u8;
+  buffer[10] = network_address as u8;
+  buffer[11] = 0x01;
+  buffer[12] = hops.len() as u8;
+
+  for (index, hop) in hops.iter().enumerate() {
+    buffer[13 + 2 * index] = (hop >> 8) as u8;
+    buffer[14 + 2 * index] = *hop as u8;
+  }
+
+  13 + 2 * hops.len()
+}
+
+#[test]
+fn test_source_route_table_learn() {
+  use super::commands::Command;
+  use super::responses::Response;
+
+  let mut table: SourceRouteTable<2, 4> = SourceRouteTable::new();
+  let mut record: [u8; 32] = [0; 32];
+  let mut buffer: [u8; 32] = [0; 32];
+
+  let length = route_record(0x0013_A200_4052_2BAA, 0x3344, &[0xAABB, 0xCCDD], &mut record);
+  table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap()).unwrap();
+  assert_eq!(table.len(), 1);
+
+  let command = table.route_to(0x0013_A200_4052_2BAA).unwrap();
+  assert_eq!(
+    command.fill_buffer(&mut buffer).unwrap(),
+    [
+      0x21, 0x00,
+      0x00, 0x13, 0xA2, 0x00, 0x40, 0x52, 0x2B, 0xAA,
+      0x33, 0x44,
+      0x00,
+      0x02,
+      0xAA, 0xBB, 0xCC, 0xDD
+    ]
+  );
+
+  // A newer record replaces the route
+  let length = route_record(0x0013_A200_4052_2BAA, 0x3344, &[0x1122], &mut record);
+  table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap()).unwrap();
+  assert_eq!(table.len(), 1);
+
+  let command = table.route_to(0x0013_A200_4052_2BAA).unwrap();
+  assert_eq!(command.fill_buffer(&mut buffer).unwrap()[13..], [0x01, 0x11, 0x22]);
+
+  assert!(table.route_to(0x0013_A200_0000_0001).is_none());
+}
+
+#[test]
+fn test_source_route_table_capacity() {
+  use super::responses::Response;
+
+  let mut table: SourceRouteTable<2, 2> = SourceRouteTable::new();
+  let mut record: [u8; 32] = [0; 32];
+
+  // Too many hops to store
+  let length = route_record(1, 0x0001, &[0x1111, 0x2222, 0x3333], &mut record);
+  let result = table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap());
+  assert_eq!(result, Err(BufferSizeError));
+  assert!(table.is_empty());
+
+  // The third node evicts the oldest route
+  for address in 1..4 {
+    let length = route_record(address, address as u16, &[0x1111], &mut record);
+    table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap()).unwrap();
+  }
+
+  assert_eq!(table.len(), 2);
+  assert!(table.route_to(1).is_none());
+  assert!(table.route_to(2).is_some());
+  assert!(table.route_to(3).is_some());
+
+  table.forget(2);
+  assert!(table.route_to(2).is_none());
+  assert_eq!(table.len(), 1);
+
+  table.clear();
+  assert!(table.is_empty());
+}
+
+#[test]
+fn test_source_route_table_eviction() {
+  use super::responses::Response;
+
+  let mut table: SourceRouteTable<3, 2> = SourceRouteTable::new();
+  let mut record: [u8; 32] = [0; 32];
+
+  for address in 1..4 {
+    let length = route_record(address, address as u16, &[0x1111], &mut record);
+    table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap()).unwrap();
+  }
+
+  // Node 1 is used and node 2 relearned, which leaves node 3 the oldest
+  assert!(table.route_to(1).is_some());
+  let length = route_record(2, 0x0002, &[0x2222], &mut record);
+  table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap()).unwrap();
+
+  let length = route_record(4, 0x0004, &[0x1111], &mut record);
+  table.learn(&RouteRecordIndicator::parse(&record[..length]).unwrap()).unwrap();
+
+  assert_eq!(table.len(), 3);
+  assert!(table.route_to(3).is_none());
+  assert!(table.route_to(1).is_some());
+  assert!(table.route_to(2).is_some());
+  assert!(table.route_to(4).is_some());
+}
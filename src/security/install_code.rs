@@ -0,0 +1,79 @@
+use super::aes_mmo_hash;
+
+/// The ways an install code can be rejected.
+#[derive(Debug, PartialEq)]
+pub enum InstallCodeError {
+  /// Install codes are 6, 8, 12 or 16 bytes long, plus a two byte CRC.
+  LengthError,
+  /// The trailing CRC does not match the install code.
+  CrcError
+}
+
+/// Computes the CRC-16 (CRC-16/X-25) that is appended to install codes.
+pub fn install_code_crc(code: &[u8]) -> u16 {
+  let mut crc: u16 = 0xFFFF;
+
+  for byte in code {
+    crc ^= *byte as u16;
+    for _ in 0..8 {
+      crc = if crc & 0x0001 != 0 { (crc >> 1) ^ 0x8408 } else { crc >> 1 };
+    }
+  }
+
+  !crc
+}
+
+/// Checks the length of an install code, including its CRC, and that the
+/// CRC stored LSB first in its last two bytes is correct.
+pub fn validate_install_code(install_code: &[u8]) -> Result<(), InstallCodeError> {
+  match install_code.len() {
+    8 | 10 | 14 | 18 => {},
+    _ => return Err(InstallCodeError::LengthError)
+  }
+
+  let (code, crc) = install_code.split_at(install_code.len() - 2);
+  if install_code_crc(code) != crc[0] as u16 + crc[1] as u16 * 256 {
+    return Err(InstallCodeError::CrcError);
+  }
+
+  Ok(())
+}
+
+/// Validates an install code, including its CRC, and derives the link key
+/// a trust center uses for the device from it.
+pub fn derive_link_key(install_code: &[u8]) -> Result<[u8; 16], InstallCodeError> {
+  validate_install_code(install_code)?;
+
+  aes_mmo_hash(install_code).map_err(|_| InstallCodeError::LengthError)
+}
+
+#[cfg(test)]
+const EXAMPLE_INSTALL_CODE: [u8; 18] = [
+  0x83, 0xFE, 0xD3, 0x40, 0x7A, 0x93, 0x97, 0x23, 0xA5, 0xC6, 0x39, 0xB2, 0x69, 0x16, 0xD5, 0x05,
+  0xC3, 0xB5
+];
+
+#[test]
+fn test_install_code_crc() {
+  assert_eq!(install_code_crc(&EXAMPLE_INSTALL_CODE[..16]), 0xB5C3);
+  assert_eq!(validate_install_code(&EXAMPLE_INSTALL_CODE), Ok(()));
+}
+
+#[test]
+fn test_derive_link_key() {
+  // The install code example from the Zigbee Base Device Behavior specification
+  assert_eq!(
+    derive_link_key(&EXAMPLE_INSTALL_CODE),
+    Ok([0x66, 0xB6, 0x90, 0x09, 0x81, 0xE1, 0xEE, 0x3C, 0xA4, 0x20, 0x6B, 0x6B, 0x86, 0x1C, 0x02, 0xBB])
+  );
+}
+
+#[test]
+fn test_install_code_errors() {
+  let mut corrupted = EXAMPLE_INSTALL_CODE;
+  corrupted[3] ^= 0x01;
+  assert_eq!(derive_link_key(&corrupted), Err(InstallCodeError::CrcError));
+
+  assert_eq!(validate_install_code(&EXAMPLE_INSTALL_CODE[..17]), Err(InstallCodeError::LengthError));
+  assert_eq!(validate_install_code(&[]), Err(InstallCodeError::LengthError));
+}
@@ -0,0 +1,70 @@
+mod aes;
+
+pub mod install_code;
+
+pub use install_code::{ InstallCodeError, derive_link_key, validate_install_code };
+
+/// Returned by `aes_mmo_hash` for messages of 8192 bytes or more, whose
+/// bit length does not fit the 16-bit length field of the padding.
+#[derive(Debug, PartialEq)]
+pub struct MessageLengthError;
+
+/// Hashes `data` with the Matyas-Meyer-Oseas construction over AES-128, as
+/// defined by the Zigbee specification. Messages must be shorter than
+/// 8192 bytes.
+pub fn aes_mmo_hash(data: &[u8]) -> Result<[u8; 16], MessageLengthError> {
+  if data.len() >= 8192 { return Err(MessageLengthError); }
+
+  let bit_length = (data.len() * 8) as u16;
+  let mut hash = [0; 16];
+
+  // The message is padded with a single one bit, zeros and its bit length
+  // so that it fills a whole number of blocks
+  let padded_length = (data.len() + 3).div_ceil(16) * 16;
+
+  let mut block = [0; 16];
+  for offset in (0..padded_length).step_by(16) {
+    for (index, byte) in block.iter_mut().enumerate() {
+      let position = offset + index;
+
+      *byte = if position < data.len() {
+        data[position]
+      } else if position == data.len() {
+        0x80
+      } else if position == padded_length - 2 {
+        (bit_length >> 8) as u8
+      } else if position == padded_length - 1 {
+        bit_length as u8
+      } else {
+        0x00
+      };
+    }
+
+    let encrypted = aes::encrypt_block(&hash, &block);
+    for index in 0..16 {
+      hash[index] = encrypted[index] ^ block[index];
+    }
+  }
+
+  Ok(hash)
+}
+
+#[test]
+fn test_aes_mmo_hash() {
+  // Test vectors from the Zigbee specification, annex C.6.1
+  assert_eq!(
+    aes_mmo_hash(&[0xC0]),
+    Ok([0xAE, 0x3A, 0x10, 0x2A, 0x28, 0xD4, 0x3E, 0xE0, 0xD4, 0xA0, 0x9E, 0x22, 0x78, 0x8B, 0x20, 0x6C])
+  );
+
+  let message: [u8; 16] = [
+    0xC0, 0xC1, 0xC2, 0xC3, 0xC4, 0xC5, 0xC6, 0xC7, 0xC8, 0xC9, 0xCA, 0xCB, 0xCC, 0xCD, 0xCE, 0xCF
+  ];
+  assert_eq!(
+    aes_mmo_hash(&message),
+    Ok([0xA7, 0x97, 0x7E, 0x88, 0xBC, 0x0B, 0x61, 0xE8, 0x21, 0x08, 0x27, 0x10, 0x9A, 0x22, 0x8F, 0x2D])
+  );
+
+  assert!(aes_mmo_hash(&[0; 8191]).is_ok());
+  assert_eq!(aes_mmo_hash(&[0; 8192]), Err(MessageLengthError));
+}
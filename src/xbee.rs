@@ -0,0 +1,340 @@
+use embedded_hal::serial::{ Read, Write };
+use embedded_hal::timer::CountDown;
+use nb::block;
+use super::buffer::{ FrameDecoder, FrameError };
+use super::commands::{ AtCommand, ByteWriter, Command };
+use super::frame::{ ApiMode, Frame, FrameBuf };
+use super::responses::{ ApiFrame, AtCommandResponse, Response, ResponseError };
+
+/// The ways the `XBee` driver can fail.
+#[derive(Debug, PartialEq)]
+pub enum XBeeError<TxError, RxError> {
+  /// Writing to the serial port failed.
+  Write(TxError),
+  /// Reading from the serial port failed.
+  Read(RxError),
+  /// A malformed frame was received.
+  Frame(FrameError),
+  /// A received frame could not be parsed.
+  Response(ResponseError),
+  /// The response to an AT command did not arrive in time.
+  Timeout
+}
+
+/// A blocking driver for an XBee connected to an `embedded-hal` serial
+/// port. It owns the buffer frames of up to `N` bytes are received into,
+/// and a queue for up to `Q` frames that arrive while `at_command` waits
+/// for its response.
+pub struct XBee<Tx, Rx, const N: usize, const Q: usize = 4> {
+  tx: Tx,
+  rx: Rx,
+  mode: ApiMode,
+  decoder: FrameDecoder,
+  buffer: [u8; N],
+  frame_id: u8,
+
+  // A ring of frames for `poll` to return before reading new ones
+  pending: [FrameBuf<N>; Q],
+  pending_start: usize,
+  pending_count: usize,
+  dropped: u32
+}
+
+impl <Tx: Write<u8>, Rx: Read<u8>, const N: usize, const Q: usize> XBee<Tx, Rx, N, Q> {
+  /// Drives an XBee configured for unescaped API mode (`AP=1`).
+  pub fn new(tx: Tx, rx: Rx) -> Self {
+    Self::with_mode(tx, rx, ApiMode::Unescaped)
+  }
+
+  /// Drives an XBee configured for the given API `mode`.
+  pub fn with_mode(tx: Tx, rx: Rx, mode: ApiMode) -> Self {
+    XBee {
+      tx,
+      rx,
+      mode,
+      decoder: FrameDecoder::new(mode),
+      buffer: [0; N],
+      frame_id: 0,
+      pending: core::array::from_fn(|_| FrameBuf::new()),
+      pending_start: 0,
+      pending_count: 0,
+      dropped: 0
+    }
+  }
+
+  /// Gives the serial port back.
+  pub fn release(self) -> (Tx, Rx) {
+    (self.tx, self.rx)
+  }
+
+  /// Returns a new frame id for a command whose response is wanted. Zero
+  /// is skipped, since it tells the XBee not to respond.
+  pub fn next_frame_id(&mut self) -> u8 {
+    self.frame_id = self.frame_id.checked_add(1).unwrap_or(1);
+    self.frame_id
+  }
+
+  /// The number of frames dropped because they arrived while `at_command`
+  /// was waiting and the queue of `Q` frames was full.
+  pub fn dropped_frames(&self) -> u32 {
+    self.dropped
+  }
+
+  /// Sends `command` as a frame, streaming it straight to the serial port.
+  pub fn send<C: Command>(&mut self, command: &C) -> Result<(), XBeeError<Tx::Error, Rx::Error>> {
+    let mut writer = SerialWriter { tx: &mut self.tx };
+    Frame::encode_command(command, self.mode, &mut writer).map_err(XBeeError::Write)?;
+
+    block!(self.tx.flush()).map_err(XBeeError::Write)
+  }
+
+  /// Returns the next frame, if any. Frames queued by `at_command` come
+  /// first, then the bytes that are available are read without blocking.
+  /// The frame borrows the driver, so it has to be dropped before the next
+  /// call.
+  pub fn poll(&mut self) -> Result<Option<ApiFrame<'_>>, XBeeError<Tx::Error, Rx::Error>> {
+    if self.pending_count > 0 {
+      let index = self.pending_start;
+      self.pending_start = (index + 1) % Q;
+      self.pending_count -= 1;
+
+      return ApiFrame::parse(self.pending[index].data()).map(Some).map_err(XBeeError::Response);
+    }
+
+    loop {
+      let received = match self.rx.read() {
+        Ok(received) => received,
+        Err(nb::Error::WouldBlock) => return Ok(None),
+        Err(nb::Error::Other(error)) => return Err(XBeeError::Read(error))
+      };
+
+      if let Some(count) = self.decoder.receive(&mut self.buffer, received).map_err(XBeeError::Frame)? {
+        return ApiFrame::parse(&self.buffer[0..count]).map(Some).map_err(XBeeError::Response);
+      }
+    }
+  }
+
+  /// Sends an AT command and waits for its response until `timer`, started
+  /// with `timeout`, expires. Other frames received in the meantime are
+  /// queued for `poll`, and counted in `dropped_frames` when the queue is
+  /// full. Malformed frames are skipped, since they may not have been the
+  /// response.
+  pub fn at_command<T: CountDown, D: Into<T::Time>>(
+    &mut self,
+    at_command: [u8; 2],
+    parameter_value: Option<&[u8]>,
+    timer: &mut T,
+    timeout: D
+  ) -> Result<AtCommandResponse<'_>, XBeeError<Tx::Error, Rx::Error>> {
+    let frame_id = self.next_frame_id();
+    self.send(&AtCommand::new(frame_id, at_command, parameter_value))?;
+
+    timer.start(timeout);
+    loop {
+      if timer.wait().is_ok() { return Err(XBeeError::Timeout); }
+
+      let received = match self.rx.read() {
+        Ok(received) => received,
+        Err(nb::Error::WouldBlock) => continue,
+        Err(nb::Error::Other(error)) => return Err(XBeeError::Read(error))
+      };
+
+      let count = match self.decoder.receive(&mut self.buffer, received) {
+        Ok(Some(count)) => count,
+        Ok(None) | Err(_) => continue
+      };
+
+      let data = &self.buffer[0..count];
+      if AtCommandResponse::respond_to(data[0]) && data.get(1) == Some(&frame_id) {
+        return AtCommandResponse::parse(&self.buffer[0..count]).map_err(XBeeError::Response);
+      }
+
+      self.queue(count);
+    }
+  }
+
+  /// Keeps the first `count` bytes of the buffer for `poll`.
+  fn queue(&mut self, count: usize) {
+    if self.pending_count == Q {
+      self.dropped = self.dropped.saturating_add(1);
+      return;
+    }
+
+    let index = (self.pending_start + self.pending_count) % Q;
+    match self.pending[index].copy_from(&self.buffer[0..count]) {
+      Ok(()) => self.pending_count += 1,
+      Err(_) => self.dropped = self.dropped.saturating_add(1)
+    }
+  }
+}
+
+/// Writes encoded frames to the serial port, blocking on every byte.
+struct SerialWriter<'t, Tx> {
+  tx: &'t mut Tx
+}
+
+impl <'t, Tx: Write<u8>> ByteWriter for SerialWriter<'t, Tx> {
+  type Error = Tx::Error;
+
+  fn write_byte(&mut self, byte: u8) -> Result<(), Tx::Error> {
+    block!(self.tx.write(byte))
+  }
+}
+
+/// The transmit half of a mock serial port, which records what is written.
+#[cfg(test)]
+struct MockTx {
+  written: std::vec::Vec<u8>
+}
+
+#[cfg(test)]
+impl Write<u8> for MockTx {
+  type Error = core::convert::Infallible;
+
+  fn write(&mut self, byte: u8) -> nb::Result<(), Self::Error> {
+    self.written.push(byte);
+    Ok(())
+  }
+
+  fn flush(&mut self) -> nb::Result<(), Self::Error> {
+    Ok(())
+  }
+}
+
+/// The receive half of a mock serial port, which has nothing to read once
+/// its input runs out.
+#[cfg(test)]
+struct MockRx {
+  input: std::collections::VecDeque<u8>
+}
+
+#[cfg(test)]
+impl Read<u8> for MockRx {
+  type Error = core::convert::Infallible;
+
+  fn read(&mut self) -> nb::Result<u8, Self::Error> {
+    self.input.pop_front().ok_or(nb::Error::WouldBlock)
+  }
+}
+
+/// A timer that expires after `wait` has been called `count` times.
+#[cfg(test)]
+struct MockTimer {
+  remaining: u32
+}
+
+#[cfg(test)]
+impl CountDown for MockTimer {
+  type Time = u32;
+
+  fn start<T: Into<u32>>(&mut self, count: T) {
+    self.remaining = count.into();
+  }
+
+  fn wait(&mut self) -> nb::Result<(), void::Void> {
+    if self.remaining == 0 { return Ok(()); }
+
+    self.remaining -= 1;
+    Err(nb::Error::WouldBlock)
+  }
+}
+
+#[cfg(test)]
+fn mock_xbee(input: &[u8]) -> XBee<MockTx, MockRx, 64> {
+  XBee::new(MockTx { written: vec![] }, MockRx { input: input.iter().copied().collect() })
+}
+
+#[test]
+fn test_xbee_send() {
+  let mut xbee = mock_xbee(&[]);
+  xbee.send(&AtCommand::new(0x52, [b'N', b'J'], None)).unwrap();
+
+  let (tx, _) = xbee.release();
+  assert_eq!(tx.written, [0x7E, 0x00, 0x04, 0x08, 0x52, 0x4E, 0x4A, 0x0D]);
+}
+
+#[test]
+fn test_xbee_poll() {
+  // A modem status frame split over two polls
+  let mut xbee = mock_xbee(&[0x7E, 0x00, 0x02]);
+  assert!(xbee.poll().unwrap().is_none());
+
+  xbee.rx.input.extend([0x8A, 0x06, 0x6F].iter());
+  match xbee.poll() {
+    Ok(Some(ApiFrame::ModemStatus(status))) => assert_eq!(status.status, super::responses::ModemStatusCode::CoordinatorStarted),
+    _ => panic!("expected a modem status")
+  }
+
+  assert!(xbee.poll().unwrap().is_none());
+}
+
+#[test]
+fn test_xbee_at_command() {
+  use super::responses::ModemStatusCode;
+
+  // A modem status and a broken frame arrive before the response
+  let mut xbee = mock_xbee(&[
+    0x7E, 0x00, 0x02, 0x8A, 0x06, 0x6F,
+    0x7E, 0x00, 0x02, 0x8A, 0x06, 0x00,
+    0x7E, 0x00, 0x06, 0x88, 0x01, 0x42, 0x44, 0x00, 0x07, 0xE9
+  ]);
+
+  let response = xbee.at_command([b'B', b'D'], None, &mut MockTimer { remaining: 0 }, 100u32).unwrap();
+  assert_eq!(response.frame_id, 0x01);
+  assert_eq!(response.command_data, Some(&[0x07][..]));
+
+  assert_eq!(xbee.tx.written, [0x7E, 0x00, 0x04, 0x08, 0x01, 0x42, 0x44, 0x70]);
+
+  // The modem status was kept for poll, the broken frame skipped
+  match xbee.poll() {
+    Ok(Some(ApiFrame::ModemStatus(status))) => assert_eq!(status.status, ModemStatusCode::CoordinatorStarted),
+    _ => panic!("expected a modem status")
+  }
+
+  assert!(xbee.poll().unwrap().is_none());
+  assert_eq!(xbee.dropped_frames(), 0);
+}
+
+#[test]
+fn test_xbee_at_command_queue_full() {
+  use super::responses::ModemStatusCode;
+
+  let input = [
+    0x7E, 0x00, 0x02, 0x8A, 0x02, 0x73,
+    0x7E, 0x00, 0x02, 0x8A, 0x06, 0x6F,
+    0x7E, 0x00, 0x06, 0x88, 0x01, 0x42, 0x44, 0x00, 0x07, 0xE9
+  ];
+  let mut xbee: XBee<_, _, 64, 1> = XBee::new(
+    MockTx { written: vec![] }, MockRx { input: input.iter().copied().collect() }
+  );
+
+  assert!(xbee.at_command([b'B', b'D'], None, &mut MockTimer { remaining: 0 }, 100u32).is_ok());
+  assert_eq!(xbee.dropped_frames(), 1);
+
+  match xbee.poll() {
+    Ok(Some(ApiFrame::ModemStatus(status))) => assert_eq!(status.status, ModemStatusCode::JoinedNetwork),
+    _ => panic!("expected a modem status")
+  }
+
+  assert!(xbee.poll().unwrap().is_none());
+}
+
+#[test]
+fn test_xbee_at_command_timeout() {
+  let mut timer = MockTimer { remaining: 0 };
+
+  // The response is cut short
+  let mut xbee = mock_xbee(&[0x7E, 0x00, 0x06, 0x88, 0x01, 0x42, 0x44]);
+  assert_eq!(xbee.at_command([b'B', b'D'], None, &mut timer, 100u32).err(), Some(XBeeError::Timeout));
+
+  // The response is complete, but arrives too late
+  let mut xbee = mock_xbee(&[0x7E, 0x00, 0x06, 0x88, 0x01, 0x42, 0x44, 0x00, 0x07, 0xE9]);
+  assert_eq!(xbee.at_command([b'B', b'D'], None, &mut timer, 9u32).err(), Some(XBeeError::Timeout));
+}
+
+#[test]
+fn test_xbee_frame_error() {
+  let mut xbee = mock_xbee(&[0x7E, 0x00, 0x02, 0x8A, 0x06, 0x00]);
+
+  assert_eq!(xbee.poll().err(), Some(XBeeError::Frame(FrameError::ChecksumError)));
+}